@@ -11,13 +11,26 @@
 //! each corresponding field or tuple-item in struct-like or tuple-like enums.
 //!
 //! Each variant of the destination enum may specify one or more variants of the source enums that
-//! should correspond to the destination variant. If multiple variants of a given source enum might
-//! correspond to the same destination variant, the destination variant must have at least 1 field
-//! or tuple item and the corresponding `TryFrom` implementations will be invoked in the order in
-//! which they appear on the destination enum until one of them succeeds.
+//! should correspond to the destination variant. If a variant of a given source enum might
+//! correspond to several destination variants, the corresponding `TryFrom` implementations will be
+//! invoked in the order in which the destination variants appear, or by descending `priority`, until
+//! one of them succeeds. A destination variant without fields or tuple items has nothing to try, so
+//! it always succeeds and any candidate after it is never tried.
 //!
 //! Effectful conversions require users to provide a struct implementing the [`WithEffects`] trait
 //! and a conversion will be generated from each source enum to the provided `effect_container`.
+//!
+//! Conversions that need access to some context, like an interner or an id allocator, may specify
+//! a `context` type. A [`FromEnumWith`] implementation is then generated instead of [`From`] and
+//! each field is converted via [`FromWith`] (or [`TryFromWith`]), which fall back to [`From`] (or
+//! [`TryFrom`](std::convert::TryFrom)) for fields that do not need the context.
+//!
+//! Persisted enums with several versions may declare each version's predecessor to form a migration
+//! chain. Every version then implements [`Migration`] and gets a [`Versioned`] enum, which holds a
+//! value of any version up to it and migrates that value to the latest version.
+//!
+//! With the `serde` feature, `#[from_enum(V1, V2, deserialize)]` generates a `Deserialize`
+//! implementation for the destination enum that falls back to the format of each source enum.
 
 #[allow(unused_imports)]
 #[macro_use]
@@ -25,6 +38,15 @@ extern crate enum_to_enum_derive;
 
 pub use enum_to_enum_derive::*;
 
+use std::convert::TryFrom;
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub mod __private {
+    pub use serde;
+    pub use serde_value;
+}
+
 /// Any struct specified as an `effect_container` for the [`from_enum`](enum_to_enum_derive::FromEnum) attribute must implement `WithEffects`.
 /// `WithEffects` specifies a container for a value, the result of some conversion, and an ordered
 /// list of effects arising from that conversion.
@@ -55,3 +77,86 @@ where
         Self::new(value, effects)
     }
 }
+
+/// Any enum annotated with `#[from_enum(SrcEnum, context = Ctx)]` implements `FromEnumWith<SrcEnum, Ctx>`.
+/// `FromEnumWith` is the context-passing counterpart of [`From`]: the conversion receives a mutable
+/// reference to a context in addition to the source value.
+pub trait FromEnumWith<Src, Ctx: ?Sized>
+where
+    Self: Sized,
+{
+    /// Converts `src` into `Self`, using `ctx` for any field conversions that need it.
+    fn from_with(src: Src, ctx: &mut Ctx) -> Self;
+}
+
+/// Field-level conversion used by context-passing conversions.
+///
+/// Any type implementing [`From<T>`] implements `FromWith<T, Ctx>` for every context, so only fields
+/// that actually need the context require a dedicated implementation.
+pub trait FromWith<T, Ctx: ?Sized>
+where
+    Self: Sized,
+{
+    /// Converts `src` into `Self`, using `ctx` as needed.
+    fn from_with(src: T, ctx: &mut Ctx) -> Self;
+}
+
+impl<T, U: From<T>, Ctx: ?Sized> FromWith<T, Ctx> for U {
+    fn from_with(src: T, _ctx: &mut Ctx) -> Self {
+        U::from(src)
+    }
+}
+
+/// Fallible field-level conversion used by context-passing, many-to-one conversions.
+///
+/// Any type implementing [`TryFrom<T>`](std::convert::TryFrom) implements `TryFromWith<T, Ctx>` for
+/// every context.
+pub trait TryFromWith<T, Ctx: ?Sized>
+where
+    Self: Sized,
+{
+    /// The type returned in the event of a conversion error.
+    type Error;
+
+    /// Attempts to convert `src` into `Self`, using `ctx` as needed.
+    fn try_from_with(src: T, ctx: &mut Ctx) -> Result<Self, Self::Error>;
+}
+
+impl<T, U: TryFrom<T>, Ctx: ?Sized> TryFromWith<T, Ctx> for U {
+    type Error = U::Error;
+
+    fn try_from_with(src: T, _ctx: &mut Ctx) -> Result<Self, Self::Error> {
+        U::try_from(src)
+    }
+}
+
+/// Any enum annotated with `#[from_enum(Previous, migration)]`, or `#[from_enum(migration)]` for the
+/// first version, implements `Migration`.
+///
+/// Each version of a persisted enum declares its predecessor and gets a version number one higher
+/// than its predecessor's, starting at 1.
+pub trait Migration {
+    /// The version number of this enum.
+    const VERSION: u32;
+
+    /// An enum holding a value of this version or of any previous version.
+    type Versioned: Versioned;
+}
+
+/// An enum, generated for each [`Migration`], that holds a value of any version up to a given one.
+///
+/// The versioned enum itself is not serializable. To persist a value, store its [`version`](Self::version)
+/// alongside the value of that version, serialized as usual. To load it, deserialize the value as
+/// the version its tag names, convert it into the versioned enum of the latest version with `From`
+/// and call [`migrate_to_latest`](Self::migrate_to_latest).
+pub trait Versioned {
+    /// The result of migrating to the latest version: the latest version itself or, for effectful
+    /// migrations, an `effect_container` holding it.
+    type Output;
+
+    /// Returns the version number of the held value, suitable as a tag for serialization.
+    fn version(&self) -> u32;
+
+    /// Migrates the held value to the latest version, applying each migration step in turn.
+    fn migrate_to_latest(self) -> Self::Output;
+}
@@ -1,30 +1,53 @@
-use proc_macro2::TokenStream as TokenStream2;
+use crate::syn_extensions::{DisplayPath, ToExprPath};
+use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::{format_ident, quote};
 use std::collections::HashMap;
-use syn::{Fields, Ident, Path, Type, Variant};
+use syn::{Field, Fields, Ident, LitInt, Pat, Path, Type, Variant, Visibility};
 
 #[derive(Debug, Clone)]
 pub struct ConversionCfg {
     pub src_case: SrcCase,
     pub dest: Variant,
+    pub field_sources: Vec<FieldSource>,
 }
 
 impl ConversionCfg {
+    /// Returns each destination field alongside the identifier its value is bound to in the source
+    /// pattern, or `None` for fields that are not converted from a source field, like provenance
+    /// fields.
+    fn field_bindings(&self) -> Vec<(&Field, Option<Ident>)> {
+        let mut bound = 0;
+        self.dest
+            .fields
+            .iter()
+            .enumerate()
+            .zip(&self.field_sources)
+            .map(|((i, field), field_source)| {
+                if *field_source != FieldSource::Field() {
+                    return (field, None);
+                }
+
+                let binding = match (&field.ident, &self.src_case.pattern) {
+                    (Some(name), _) => name.clone(),
+                    (None, Some(pattern)) => pattern.bindings[bound].clone(),
+                    (None, None) => format_ident!("arg{}", i),
+                };
+                bound += 1;
+                (field, Some(binding))
+            })
+            .collect()
+    }
+
+    /// Whether any destination field is converted from a source field.
+    pub fn has_bound_fields(&self) -> bool {
+        self.field_sources.contains(&FieldSource::Field())
+    }
+
     pub fn each_arg<F: Fn(&Ident, &Type) -> TokenStream2>(&self, xform: F) -> Vec<TokenStream2> {
-        match &self.dest.fields {
-            Fields::Unit => vec![],
-            Fields::Named(named) => named
-                .named
-                .iter()
-                .map(|field| xform(field.ident.as_ref().unwrap(), &field.ty))
-                .collect(),
-            Fields::Unnamed(unnamed) => unnamed
-                .unnamed
-                .iter()
-                .enumerate()
-                .map(|(i, field)| xform(&format_ident!("arg{}", i), &field.ty))
-                .collect(),
-        }
+        self.field_bindings()
+            .into_iter()
+            .filter_map(|(field, binding)| binding.map(|binding| xform(&binding, &field.ty)))
+            .collect()
     }
 
     pub fn to_args<T: Fn(&Ident, &Type) -> TokenStream2>(&self, xform: T) -> TokenStream2 {
@@ -52,68 +75,263 @@ impl ConversionCfg {
         }
     }
 
-    pub fn to_case_match(
-        &self,
-        dest: &Ident,
-        use_try_from: bool,
-        has_effect: bool,
-    ) -> TokenStream2 {
+    pub fn to_case_match(&self, dest: &Ident, src: &Path, has_effect: bool) -> TokenStream2 {
         let dest_case = &self.dest.ident;
         let fields = &self.dest.fields;
 
-        match (fields, use_try_from) {
-            (Fields::Unit, true) => {
-                panic!("multiple source options found for a single destination and the source does not have a field to try_from");
+        let values = self
+            .field_bindings()
+            .into_iter()
+            .zip(&self.field_sources)
+            .map(|((field, binding), field_source)| {
+                let value = match (binding, field_source) {
+                    (Some(id), _) if has_effect => {
+                        let id_val = hygienic_suffixed(&id, "value");
+                        quote! { #id_val }
+                    }
+                    (Some(id), _) => quote! { #id },
+                    (None, FieldSource::SourceVariant()) => {
+                        let name = match &self.src_case.case_name {
+                            CaseName::Variant(src_case) => src_case.to_string(),
+                            CaseName::Wildcard() => {
+                                unreachable!("source_variant is rejected for wildcard cases")
+                            }
+                        };
+                        quote! { #name }
+                    }
+                    (None, _) => {
+                        let name = src.display_path();
+                        quote! { #name }
+                    }
+                };
+
+                match &field.ident {
+                    Some(name) => quote! { #name: #value },
+                    None => value,
+                }
+            });
+
+        match fields {
+            Fields::Unit => quote! {
+                #dest::#dest_case
+            },
+            Fields::Named(_) => quote! {
+                #dest::#dest_case { #(#values),* }
+            },
+            Fields::Unnamed(_) => quote! {
+                #dest::#dest_case (#(#values),*)
+            },
+        }
+    }
+
+    /// Returns a pattern matching the destination variant, binding the fields that correspond to
+    /// source fields and ignoring the rest.
+    pub fn to_dest_pattern(&self, dest: &Ident) -> TokenStream2 {
+        let dest_case = &self.dest.ident;
+        let args = self.field_bindings().into_iter().map(|(field, binding)| {
+            match (&field.ident, binding) {
+                (Some(name), None) => quote! { #name: _ },
+                (None, None) => quote! { _ },
+                (_, Some(binding)) => quote! { #binding },
             }
-            (Fields::Unit, false) => {
+        });
+
+        match &self.dest.fields {
+            Fields::Unit => quote! { #dest::#dest_case },
+            Fields::Named(_) => quote! { #dest::#dest_case { #(#args),* } },
+            Fields::Unnamed(_) => quote! { #dest::#dest_case (#(#args),*) },
+        }
+    }
+
+    /// Whether the guard receives the whole source value, rather than the source fields, because no
+    /// field flows into the destination variant.
+    fn guards_whole_src(&self) -> bool {
+        self.src_case.guard.is_some() && !self.has_bound_fields()
+    }
+
+    pub fn to_src_pattern(&self, src: &Path) -> TokenStream2 {
+        let pattern = self.to_case_pattern(src);
+        if self.guards_whole_src() {
+            let guarded = hygienic("guarded");
+            return quote! {
+                #guarded @ #pattern
+            };
+        }
+
+        pattern
+    }
+
+    fn to_case_pattern(&self, src: &Path) -> TokenStream2 {
+        if let Some(CasePattern { pat, .. }) = &self.src_case.pattern {
+            let src = src.to_expr_path();
+            return quote! {
+                #src::#pat
+            };
+        }
+
+        match &self.src_case.case_name {
+            CaseName::Variant(src_case) if self.src_case.flatten => {
+                let src = src.to_expr_path();
+                let args = self.each_arg(|arg, _| quote! { #arg });
+
                 quote! {
-                    #dest::#dest_case
+                    #(#args)* @ #src::#src_case { .. }
                 }
             }
-            (Fields::Named(_), _) => {
-                let args = self.to_wrapped_args(|id| {
-                    if has_effect {
-                        let id_val = format_ident!("{}_value", id);
-                        quote! {
-                            #id: #id_val
-                        }
-                    } else {
-                        quote! {
-                            #id
-                        }
-                    }
-                });
+            CaseName::Variant(src_case) if !self.has_bound_fields() => {
+                // nothing flows into the destination, so any fields of the source case are ignored
+                let src = src.to_expr_path();
 
                 quote! {
-                    #dest::#dest_case #args
+                    #src::#src_case { .. }
                 }
             }
-            (Fields::Unnamed(_), _) => {
-                let args = self.to_wrapped_args(|id| {
-                    if has_effect {
-                        let id_val = format_ident!("{}_value", id);
-                        quote! {
-                            #id_val
-                        }
-                    } else {
-                        quote! {
-                            #id
-                        }
-                    }
-                });
+            CaseName::Variant(src_case) => {
+                let src = src.to_expr_path();
+                let args = self.to_wrapped_args(|arg| quote! { #arg });
 
                 quote! {
-                    #dest::#dest_case #args
+                    #src::#src_case #args
+                }
+            }
+            CaseName::Wildcard() => {
+                let args = self.each_arg(|arg, _| quote! { #arg });
+                match args.as_slice() {
+                    [arg] => arg.clone(),
+                    _ => quote! { _ },
                 }
             }
         }
     }
+
+    pub fn to_guard(&self) -> Option<TokenStream2> {
+        self.src_case.guard.as_ref().map(|guard| {
+            let args = if self.guards_whole_src() {
+                let guarded = hygienic("guarded");
+                quote! { &#guarded }
+            } else {
+                self.to_args(|arg, _| quote! { &#arg })
+            };
+            quote! {
+                if #guard(#args)
+            }
+        })
+    }
+
+    pub fn to_reverse_case_match(&self, src: &Path) -> Option<TokenStream2> {
+        let src_case = match &self.src_case {
+            SrcCase {
+                case_name: CaseName::Variant(src_case),
+                pattern: None,
+                guard: None,
+                flatten: false,
+                ..
+            } => src_case,
+            _ => return None,
+        };
+        let src = src.to_expr_path();
+        let args = match &self.dest.fields {
+            Fields::Named(_) => self.to_wrapped_args(|id| quote! { #id: #id.into() }),
+            _ => self.to_wrapped_args(|id| quote! { #id.into() }),
+        };
+
+        Some(quote! {
+            #src::#src_case #args
+        })
+    }
+}
+
+/// Returns an identifier for a variable that we introduce in generated code. Its mixed-site span
+/// keeps it apart from the bindings of source fields, so that a field named, say, `value` or `ctx`
+/// can neither shadow it nor be shadowed by it.
+pub fn hygienic(name: &str) -> Ident {
+    Ident::new(name, Span::mixed_site())
+}
+
+/// Returns a hygienic identifier for a variable derived from the binding `ident`, like `a_value`.
+/// Being hygienic, it never collides with another binding, even one named `a_value`.
+pub fn hygienic_suffixed(ident: &Ident, suffix: &str) -> Ident {
+    format_ident!("{}_{}", ident, suffix, span = Span::mixed_site())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConversionTrait {
+    pub path: Path,
+    pub method: Ident,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConversionFn {
+    pub name: Ident,
+    pub vis: Visibility,
+}
+
+/// How the conversion from a source enum is generated, as configured by the from_enum attribute
+/// that lists it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConversionSettings {
+    pub effect_holder_name: Option<Path>,
+    pub context: Option<Type>,
+    pub conversion_trait: Option<ConversionTrait>,
+    pub conversion_fn: Option<ConversionFn>,
+    pub partial: bool,
+}
+
+/// A version in a migration chain, as declared by `#[from_enum(Previous, migration)]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MigrationStep {
+    /// The previous version, if this is not the first one.
+    pub previous: Option<Path>,
+    /// The settings of the conversion from the previous version.
+    pub settings: ConversionSettings,
 }
 
 #[derive(Debug, Clone)]
 pub struct SrcCase {
-    pub case_name: Ident,
-    pub fallible: bool,
+    pub case_name: CaseName,
+    pub pattern: Option<CasePattern>,
+    pub guard: Option<Path>,
+    /// Whether the whole source enum is converted into the single field of the destination variant,
+    /// which holds a nested enum, rather than the fields of the source case.
+    pub flatten: bool,
+    /// Candidates with a higher priority are tried first, before candidates without a priority.
+    pub priority: Option<LitInt>,
+    /// A hint that the field conversions of this candidate never fail, so that any candidate tried
+    /// after it for the same source case is unreachable.
+    pub infallible: Option<Ident>,
+}
+
+impl SrcCase {
+    pub fn priority(&self) -> Option<u32> {
+        self.priority
+            .as_ref()
+            .and_then(|priority| priority.base10_parse().ok())
+    }
+}
+
+/// An explicit pattern for a source case, like `Event(Kind::Click, pos)`, and the identifiers it
+/// binds, which flow into the destination fields.
+#[derive(Debug, Clone)]
+pub struct CasePattern {
+    pub pat: Pat,
+    pub bindings: Vec<Ident>,
+}
+
+/// Where the value of a destination field comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldSource {
+    /// Converted from the corresponding source field.
+    Field(),
+    /// The name of the source variant, as a `&'static str`.
+    SourceVariant(),
+    /// The path of the source enum, as a `&'static str`.
+    SourceEnum(),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CaseName {
+    Variant(Ident),
+    Wildcard(),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -13,34 +13,177 @@ mod error;
 mod ir;
 mod merge_in;
 mod parser;
+mod rename;
+mod suggest;
 mod syn_extensions;
 
 use crate::error::Error;
-use crate::ir::ConversionCfg;
+use crate::ir::{
+    hygienic, hygienic_suffixed, CaseName, ConversionCfg, ConversionFn, ConversionTrait,
+};
 use crate::parser::EnumParser;
+use crate::syn_extensions::DisplayPath;
 use proc_macro::TokenStream;
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::{format_ident, quote, quote_spanned};
-use syn::spanned::Spanned;
+use std::collections::HashSet;
+use syn::{
+    parse_quote, spanned::Spanned, Attribute, Error as SynError, Fields, Ident, ItemEnum, Meta,
+    NestedMeta, Path, Visibility,
+};
 
 /// You can add `#[derive(FromEnum)]` to any enum to generate a possibly effectful [`From`]
 /// implementation to convert from other source enums to the annotated destination enum.
 ///
 /// # `from_enum`
 /// - You **must** annotate the destination enum with `#[from_enum(SrcEnum1, SrcEnum2, ...)]`.
+/// - You *may* repeat `from_enum`, like `#[from_enum(SrcEnum1, effect_container = Logged)]` and
+///   `#[from_enum(SrcEnum2, partial)]`. The options below apply only to the source enums of the same
+///   `from_enum`, so each source enum may be converted differently. A source enum may be listed more
+///   than once, but only with the same options.
 /// - You *may* include `effect_container = YourEffectContainer`, like this:
-/// `#[from_enum(SrcEnum1, effect_container = YourEffectContainer)]`. If `effect_container` is
-/// specified, the conversion will be `From<SrcEnum1> for YourEffectContainer<Value = DestEnum>`.
-/// `YourEffectContainer` **must** implement `enum_to_enum::WithEffects`.
-/// If `effect_container` is not specified, the conversion will be `From<SrcEnum1> for DestEnum`.
+///   `#[from_enum(SrcEnum1, effect_container = YourEffectContainer)]`. If `effect_container` is
+///   specified, the conversion will be `From<SrcEnum1> for YourEffectContainer<Value = DestEnum>`.
+///   `YourEffectContainer` **must** implement `enum_to_enum::WithEffects`.
+///   If `effect_container` is not specified, the conversion will be `From<SrcEnum1> for DestEnum`.
+/// - You *may* include `context = YourContext`, like this:
+///   `#[from_enum(SrcEnum1, context = YourContext)]`. If `context` is specified, the conversion will
+///   be `enum_to_enum::FromEnumWith<SrcEnum1, YourContext> for DestEnum` and fields will be
+///   converted with `enum_to_enum::FromWith`, which falls back to `From` for fields that do not
+///   need the context.
+/// - You *may* include `trait = YourTrait, method = your_method`, like this:
+///   `#[from_enum(SrcEnum1, trait = my::Convert, method = convert)]`. If `trait` is specified, the
+///   conversion will be `my::Convert<SrcEnum1> for DestEnum`, with `fn convert(src: SrcEnum1) -> DestEnum`,
+///   instead of `From<SrcEnum1> for DestEnum`, and fields will be converted with `my::Convert` as well.
+///   Many-to-one conversions still rely on `TryFrom` to pick a candidate. `trait` may not be combined
+///   with `context`.
+/// - You *may* include `fn = your_fn`, like this: `#[from_enum(SrcEnum1, fn = from_legacy)]`. If `fn`
+///   is specified, no trait is implemented. Instead, we generate an inherent
+///   `pub fn from_legacy(src: SrcEnum1) -> DestEnum` on the destination enum. The visibility may be
+///   changed with `vis`, e.g. `vis = "pub(crate)"`. `fn` requires exactly one source enum.
+/// - You *may* include `remote = "other_crate::SrcEnum"` to declare the destination enum as a mirror
+///   of a foreign enum, much like serde's remote derive. `other_crate::SrcEnum` is then a source
+///   enum like any other and, because the mirror describes the shape of both enums, we also generate
+///   `From<DestEnum> for other_crate::SrcEnum`. Each remote variant must correspond to exactly one
+///   mirror variant. Chaining mirrors lets you convert between two enums that you do not own.
+/// - You *may* include `rename_all`, `strip_prefix` and `strip_suffix`, like this:
+///   `#[from_enum(SrcEnum1, rename_all = "SCREAMING_SNAKE_CASE", strip_prefix = "EventType")]`, to
+///   derive the names of the source variants that unannotated destination variants are converted
+///   from. The prefix and suffix are added to the destination variant name, which is then converted
+///   to the `rename_all` case convention: one of `"lowercase"`, `"UPPERCASE"`, `"PascalCase"`,
+///   `"camelCase"`, `"snake_case"` or `"SCREAMING_SNAKE_CASE"`. Here, `Click` converts from
+///   `EVENT_TYPE_CLICK`. The rules only apply to the source enums of the same `from_enum` and an
+///   explicit `from_case` overrides them.
+/// - You *may* include `via = Intermediate`, like this: `#[from_enum(V1, via = V2)]`, to convert
+///   from `V1` through an intermediate enum, as `V3::from(V2::from(v1))`, without mapping every
+///   variant of `V1` yourself. Only explicit mappings, like `#[from_case(V1 = Legacy)]`, bypass the
+///   intermediate enum. In effectful conversions, the effects of both conversions are concatenated.
+///   When another `from_enum` lists the intermediate enum, the second conversion is the one it
+///   configures, say `fn = from_v2`, which must then be infallible and use the same
+///   `effect_container` and `context`. `via` may not be combined with `rename_all`, `strip_prefix`
+///   or `strip_suffix`.
+/// - You *may* include `migration`, like this: `#[from_enum(EventV2, migration)]`, to declare the
+///   destination enum, say `EventV3`, as the next version of a persisted enum, with `EventV2` as its
+///   predecessor, or `#[from_enum(migration)]` for the first version. We then implement
+///   `enum_to_enum::Migration` for `EventV3`, with a `VERSION` one higher than its predecessor's, and generate an `EventV3Versioned`
+///   enum, which holds either an `EventV3` or, in its `Previous` variant, any previous version.
+///   `EventV3Versioned` implements `From` for every version, nesting older ones in `Previous` for
+///   you, and `Debug`, `Clone`, `PartialEq` and `Eq` whenever every version does. Its
+///   `enum_to_enum::Versioned` implementation provides `version()`, a tag for serialization, and
+///   `migrate_to_latest()`, which applies each conversion step in turn and, with an
+///   `effect_container`, gathers the effects of every step. All versions must then use the same
+///   `effect_container`. `migration` may not be combined with `context`, `trait`, `fn` or `partial`,
+///   and only one `from_enum` may declare it, though others may list further source enums.
+/// - You *may* include `partial`, like this: `#[from_enum(SrcEnum1, partial)]`, to convert from only
+///   some variants of the source enum. The conversion will then be `TryFrom<SrcEnum1> for DestEnum`,
+///   which hands back the source enum as the error for any unmapped variant. `partial` may not be
+///   combined with `context`, `trait` or `fn`.
+/// - With the `serde` feature of `enum_to_enum`, you *may* include `deserialize`, like this:
+///   `#[from_enum(EventV1, LegacyEvent, deserialize)]`, to implement `serde::Deserialize` for the
+///   destination enum instead of deriving it. Input is first deserialized in the destination's own
+///   format, honoring its `#[serde(...)]` attributes, and then as each source enum in declaration
+///   order, converted with the generated `From`. If every attempt fails, the error lists each of
+///   them. Source enums must implement `Deserialize`. Since the input is buffered before each
+///   attempt, which asks the deserializer for whatever it finds, the fallback only works with
+///   self-describing formats, like JSON, YAML or MessagePack, and not with formats like bincode.
+///   Since it applies to every source enum, only the first `from_enum` may include `deserialize`,
+///   which may not be combined with `effect_container`, `context`, `trait`, `fn` or `partial`.
+/// - We warn about likely mistakes: candidates that follow an `infallible` candidate, source cases
+///   listed more than once for the same variant and destination variants that no source variant
+///   converts into. You *may* include `strict`, like this: `#[from_enum(SrcEnum1, strict)]`, to
+///   report them as errors instead. `strict` applies to the whole enum, so only the first
+///   `from_enum` may include it.
 ///
 /// # `from_case`
 /// - You *may* also annotate any variant of the destination enum with `#[from_case(SomeCase)]` to
-/// convert from `SomeCase` on all source enums to the annotated variant.
+///   convert from `SomeCase` on all source enums to the annotated variant.
 /// - You *may* annotate `#[from_case(DefaultCase, source_enum_1 = SourceEnum1Case)]` to convert from
-/// `SourceEnum1Case` of `source_enum_1` to the annotated case and from `DefaultCase` of all other source
-/// enums to the annotated case.
+///   `SourceEnum1Case` of `source_enum_1` to the annotated case and from `DefaultCase` of all other source
+///   enums to the annotated case.
+/// - You *may* annotate `#[from_case(_)]` or `#[from_case(source_enum_1 = _)]` to convert from every
+///   otherwise unmapped variant to the annotated case. The annotated case must have at most one field,
+///   which is converted from the whole source enum. This is required for `#[non_exhaustive]` source
+///   enums, like `std::io::ErrorKind`.
+/// - You *may* follow a case with a pattern for its fields, like `#[from_case(Event(Kind::Click, pos))]`
+///   or `#[from_case(Src = Http { status: 500..=599, body, .. })]`, to split a single source variant
+///   across several destination variants by its contents. The identifiers bound by the pattern flow
+///   into the destination fields: by name for struct-like variants and in order for tuple-like
+///   variants. Patterns are tried in declaration order, before any plain case of the same variant.
+/// - You *may* add a priority, like `#[from_case(Case1, priority = 10)]`, to control the order in
+///   which the candidates for a source case are tried. Candidates with a higher priority are tried
+///   first, followed by candidates without a priority, in declaration order. Two candidates for the
+///   same source case may not have the same priority.
+/// - You *may* convert several source cases into a variant without fields, like
+///   `#[from_case(Paused, Stopped)]` on `Inactive`, whatever the fields of the source cases. A
+///   variant without fields has nothing to try_from, so when it is one of several candidates for a
+///   source case, it always succeeds and any candidate after it is never tried. Use a priority or a
+///   guard to decide which candidate wins.
+/// - You *may* mark a candidate as `infallible`, like `#[from_case(Case1, infallible)]`, when its
+///   field conversions never fail, say because they come from `From`. Any candidate tried after it
+///   for the same source case can never be reached, so we warn about it.
+/// - You *may* look through a wrapper variant into a nested enum, like
+///   `#[from_case(Net(NetEvent::Connect))]` or `#[from_case(Src = Net(NetEvent::Connect))]`, to convert
+///   each variant of the nested enum into its own destination variant. Unless the pattern binds
+///   identifiers itself, the fields of the nested variant flow into the destination fields, just like
+///   those of a plain case.
+/// - You *may* add a guard, like `#[from_case(Transfer, if = rules::is_large)]`, to only convert from
+///   the listed cases when the predicate holds. The predicate receives references to the source
+///   fields that flow into the destination fields, in the order of the destination fields. When no
+///   source field flows into the variant, say because it has no fields, the predicate instead
+///   receives a reference to the whole source value, like `fn is_large(transfer: &Src) -> bool`.
+///   Guarded cases are tried in declaration order, before any unguarded case.
+/// - You *may* annotate a variant with a single field that holds a nested enum, like `Net(NetEvent)`,
+///   with `#[from_case(flatten(Connect, Disconnect))]` to convert the listed source variants into the
+///   nested enum, via its own `TryFrom` conversion, and wrap them in the annotated variant. The nested
+///   enum is typically derived with `#[from_enum(SrcEnum1, partial)]`. Since it takes the whole
+///   source variant, no other variant may convert from a listed variant without a pattern or guard.
+///   A bare `#[from_case(flatten)]`
+///   instead offers every otherwise unmapped source variant to each flattened variant in turn, in
+///   declaration order. Any source variant that the flattened enums do not accept is handed back as
+///   the error of the conversion, so `flatten` requires `partial` on the `from_enum` of each source
+///   enum it applies to. A bare `flatten` may not be combined with a wildcard.
+/// - You *may* exclude a variant from some source enums with `#[from_case(except(Src2))]`, so that
+///   an unannotated variant, or a case for all source enums, does not expect a same-named variant in
+///   `Src2`. A variant marked `#[from_case(skip)]` is never converted into at all, so we do not warn
+///   that no source variant converts into it. A mirror of a remote enum may not use either.
 /// - Without any `from_case` annotation, the we default to converting from same-named variants.
+/// - Variants behind a `#[cfg]` that is off are removed before we see the enum, so they take part in
+///   no conversion. Their source cases fall back to any other candidate for them or, failing that,
+///   to a wildcard. The same goes for fields, which a source pattern must then also lack, typically
+///   by being behind the same `#[cfg]`.
+///
+/// # `from_field`
+/// - You *may* annotate a `&'static str` field of a destination variant with
+///   `#[from_field(source_variant)]` to record the name of the source variant that it was converted
+///   from, like `"Case2"`. This is handy for many-to-one conversions, which otherwise lose that
+///   information. `source_variant` may not be used with wildcard cases.
+/// - You *may* annotate a `&'static str` field with `#[from_field(source_enum)]` to record the
+///   source enum that it was converted from, like `"other_crate::SrcEnum"`.
+/// - Annotated fields have no corresponding source field, so they are skipped when matching and
+///   converting source fields.
+///
+/// Source enums may be generic, like `Option<Foo>` or `Result<Foo, Bar>`, and their variants, like
+/// `Some`, `None`, `Ok` and `Err`, may be used as cases.
 ///
 /// # Examples
 ///
@@ -254,7 +397,68 @@ use syn::spanned::Spanned;
 ///
 /// # }
 /// ```
-#[proc_macro_derive(FromEnum, attributes(from_enum, from_case))]
+///
+/// ## Context-passing conversion
+/// Context-passing conversion, demonstrating a field conversion that needs a context alongside a
+/// field that falls back to `From`.
+///
+/// ```rust
+/// # #[macro_use] extern crate enum_to_enum_derive;
+/// # fn main () {
+/// use enum_to_enum::{FromEnum, FromEnumWith};
+///
+/// #[derive(Debug)]
+/// enum Src {
+///     Case1(String),
+///     Case2(String, u8),
+/// }
+///
+/// #[derive(FromEnum, Debug, PartialEq, Eq)]
+/// #[from_enum(Src, context = Interner)]
+/// enum Dest {
+///     Case1(Symbol),
+///     Case2(Symbol, u8),
+/// }
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// struct Symbol(usize);
+///
+/// #[derive(Default)]
+/// struct Interner {
+///     strings: Vec<String>,
+/// }
+///
+/// impl enum_to_enum::FromWith<String, Interner> for Symbol {
+///     fn from_with(src: String, interner: &mut Interner) -> Symbol {
+///         let existing = interner.strings.iter().position(|s| *s == src);
+///         Symbol(existing.unwrap_or_else(|| {
+///             interner.strings.push(src);
+///             interner.strings.len() - 1
+///         }))
+///     }
+/// }
+///
+/// let mut interner = Interner::default();
+///
+/// assert_eq!(
+///     Dest::from_with(Src::Case1(String::from("hello")), &mut interner),
+///     Dest::Case1(Symbol(0)),
+/// );
+///
+/// assert_eq!(
+///     Dest::from_with(Src::Case2(String::from("hello"), 7), &mut interner),
+///     Dest::Case2(Symbol(0), 7),
+/// );
+/// # }
+/// ```
+#[cfg_attr(
+    not(feature = "serde"),
+    proc_macro_derive(FromEnum, attributes(from_enum, from_case, from_field))
+)]
+#[cfg_attr(
+    feature = "serde",
+    proc_macro_derive(FromEnum, attributes(from_enum, from_case, from_field, serde))
+)]
 pub fn derive_enum_from(input: TokenStream) -> TokenStream {
     let result = from_enum_internal(input.into()).unwrap_or_else(|err| {
         let errors = err.into_compile_errors();
@@ -269,71 +473,143 @@ pub fn derive_enum_from(input: TokenStream) -> TokenStream {
 fn from_enum_internal(input: TokenStream2) -> Result<TokenStream2, Error> {
     let parser = EnumParser::parse(input)?;
 
-    let dest = &parser.dest;
-    let effect_holder_name = &parser.effect_holder_name.as_ref();
-    let has_effect = effect_holder_name.is_some();
-    let conversion_cfgs_by_src_case_by_src = parser.conversion_cfgs_by_src_case_by_src();
-    let result_wrapper = |case_match: TokenStream2,
-                          conversion_cfg: &ConversionCfg,
-                          should_return: bool| {
-        let ret = if should_return {
-            quote! { return }
-        } else {
-            quote! {}
-        };
+    let lints = parser.lints();
+    if parser.strict && !lints.is_empty() {
+        return Err(lints
+            .into_iter()
+            .map(Error::from)
+            .collect::<Vec<_>>()
+            .into());
+    }
+    let warnings = lints
+        .iter()
+        .map(|lint| warning(lint.span(), &lint.to_string()));
 
-        effect_holder_name
-            .map(|n| {
-                let chains = conversion_cfg.each_arg(|arg, _| {
-                    let arg_effects = format_ident!("{}_effects", arg);
-                    quote! { .chain(#arg_effects) }
-                });
-                let vals_and_effects = conversion_cfg.each_arg(|arg, _| {
-                    let arg_val = format_ident!("{}_value", arg);
-                    let arg_effects = format_ident!("{}_effects", arg);
-                    quote_spanned! {n.span()=>
-                        let (#arg_val, #arg_effects) = #arg.into_value_and_effects();
+    let dest = &parser.dest;
+    let conversion_arms_by_src = parser.conversion_arms_by_src();
+    let impls =
+        conversion_arms_by_src
+            .iter()
+            .map(|(src_name, conversion_arms)| {
+                let settings = parser.settings(src_name);
+                let effect_holder_name = &settings.effect_holder_name.as_ref();
+                let has_effect = effect_holder_name.is_some();
+                let context = settings.context.as_ref();
+                let conversion_trait = settings.conversion_trait.as_ref();
+                let conversion_fn = settings.conversion_fn.as_ref();
+                let partial = settings.partial;
+                let src = hygienic("src");
+                let ctx = hygienic("ctx");
+                let value = hygienic("value");
+                let effects = hygienic("effects");
+                let ok = |converted: TokenStream2| {
+                    if partial {
+                        quote! { std::result::Result::Ok(#converted) }
+                    } else {
+                        converted
                     }
-                });
-                quote_spanned! {n.span()=>
-                    #(#vals_and_effects)*
-                    let value = #case_match;
-                    let effects = std::iter::empty()#(#chains)*.collect::<Vec<_>>().into_boxed_slice();
+                };
+                let result_wrapper = |case_match: TokenStream2,
+                                      conversion_cfg: &ConversionCfg,
+                                      should_return: bool| {
+                    let ret = if should_return {
+                        quote! { return }
+                    } else {
+                        quote! {}
+                    };
 
-                    #ret #n::compose_from(value, effects)
-                }
-            })
-            .unwrap_or_else(|| quote! { #ret #case_match})
-    };
+                    effect_holder_name
+                        .map(|n| {
+                            let chains = conversion_cfg.each_arg(|arg, _| {
+                                let arg_effects = hygienic_suffixed(arg, "effects");
+                                quote! { .chain(#arg_effects) }
+                            });
+                            let composed = ok(quote_spanned! {n.span()=> #n::compose_from(#value, #effects) });
+                            let vals_and_effects = conversion_cfg.each_arg(|arg, _| {
+                                let arg_val = hygienic_suffixed(arg, "value");
+                                let arg_effects = hygienic_suffixed(arg, "effects");
+                                quote_spanned! {n.span()=>
+                                    let (#arg_val, #arg_effects) = #arg.into_value_and_effects();
+                                }
+                            });
+                            quote_spanned! {n.span()=>
+                                #(#vals_and_effects)*
+                                let #value = #case_match;
+                                let #effects = std::iter::empty()#(#chains)*.collect::<Vec<_>>().into_boxed_slice();
 
-    let impls =
-        conversion_cfgs_by_src_case_by_src
-            .iter()
-            .map(|(src_name, conversion_cfgs_by_src_case)| {
-                let cases = conversion_cfgs_by_src_case
+                                #ret #composed
+                            }
+                        })
+                        .unwrap_or_else(|| {
+                            let case_match = ok(case_match);
+                            quote! { #ret #case_match }
+                        })
+                };
+
+                let cases = conversion_arms
                     .iter()
-                    .map(|(case, conversion_cfgs)| {
+                    .map(|conversion_cfgs| {
+                        let example_conversion_cfg = conversion_cfgs.first().unwrap();
+                        if example_conversion_cfg.src_case.flatten
+                            && example_conversion_cfg.src_case.case_name == CaseName::Wildcard()
+                        {
+                            return flatten_fallback_arm(
+                                dest,
+                                src_name,
+                                conversion_cfgs,
+                                effect_holder_name,
+                                &result_wrapper,
+                            );
+                        }
+
+                        // a candidate without fields to try_from always succeeds, so the first one
+                        // wins and ends the candidates to try
+                        let conversion_cfgs = match conversion_cfgs
+                            .iter()
+                            .position(|conversion_cfg| !conversion_cfg.has_bound_fields())
+                        {
+                            Some(i) => &conversion_cfgs[..=i],
+                            None => &conversion_cfgs[..],
+                        };
+                        let example_conversion_cfg = conversion_cfgs
+                            .iter()
+                            .find(|conversion_cfg| conversion_cfg.has_bound_fields())
+                            .unwrap_or(example_conversion_cfg);
                         let use_try_from = conversion_cfgs.len() > 1;
                         let conversions = conversion_cfgs.iter().map(|conversion_cfg| {
                             let case_match =
-                                conversion_cfg.to_case_match(dest, use_try_from, has_effect);
+                                conversion_cfg.to_case_match(dest, src_name, has_effect);
 
-                            if use_try_from {
+                            if use_try_from && !conversion_cfg.has_bound_fields() {
+                                let res = result_wrapper(case_match, conversion_cfg, true);
+                                quote! {
+                                    #res;
+                                }
+                            } else if use_try_from {
                                 let arg_let = conversion_cfg.each_arg(|arg, ty| {
-                                    let arg_res = format_ident!("{}_res", &arg);
+                                    let arg_res = hygienic_suffixed(arg, "res");
                                     let typ = effect_holder_name
                                         .map(|n| {
                                             quote! { #n<#ty> }
                                         })
                                         .unwrap_or_else(|| quote! { #ty });
 
-                                    quote! {
-                                        let #arg_res: std::result::Result<#typ, _> = #arg.clone().try_into();
+                                    // span the conversion with the destination field so that a
+                                    // missing impl is reported on the field rather than the derive
+                                    let field_arg = respanned(arg, ty.span());
+                                    let try_into = context
+                                        .map(|_| {
+                                            quote_spanned! {ty.span()=> enum_to_enum::TryFromWith::try_from_with(#field_arg.clone(), #ctx) }
+                                        })
+                                        .unwrap_or_else(|| quote_spanned! {ty.span()=> #field_arg.clone().try_into() });
+
+                                    quote_spanned! {ty.span()=>
+                                        let #arg_res: std::result::Result<#typ, _> = #try_into;
                                     }
                                 });
                                 let lhs = conversion_cfg.to_args(|arg, _| quote! { Ok(#arg) });
                                 let rhs = conversion_cfg.to_args(|arg, _| {
-                                    let arg_res = format_ident!("{}_res", &arg);
+                                    let arg_res = hygienic_suffixed(arg, "res");
                                     quote! { #arg_res }
                                 });
                                 let res = result_wrapper(case_match, conversion_cfg, true);
@@ -352,8 +628,30 @@ fn from_enum_internal(input: TokenStream2) -> Result<TokenStream2, Error> {
                                         })
                                         .unwrap_or_else(|| quote! { #ty });
 
-                                    quote! {
-                                        let #arg: #full_type = #arg.into();
+                                    let field_arg = respanned(arg, ty.span());
+                                    let into = match (context, conversion_trait) {
+                                        // the parser only accepts a flatten in a partial
+                                        // conversion, which hands back the source variant that the
+                                        // flattened enum does not accept
+                                        _ if conversion_cfg.src_case.flatten => quote! {
+                                            match <#full_type as std::convert::TryFrom<#src_name>>::try_from(#arg) {
+                                                std::result::Result::Ok(#arg) => #arg,
+                                                std::result::Result::Err(#src) => {
+                                                    return std::result::Result::Err(#src);
+                                                }
+                                            }
+                                        },
+                                        (Some(_), _) => {
+                                            quote_spanned! {ty.span()=> enum_to_enum::FromWith::from_with(#field_arg, #ctx) }
+                                        }
+                                        (None, Some(ConversionTrait { path, method })) => {
+                                            quote_spanned! {ty.span()=> <#full_type as #path<_>>::#method(#field_arg) }
+                                        }
+                                        (None, None) => quote_spanned! {ty.span()=> #field_arg.into() },
+                                    };
+
+                                    quote_spanned! {ty.span()=>
+                                        let #arg: #full_type = #into;
                                     }
                                 });
                                 let res = result_wrapper(case_match, conversion_cfg, false);
@@ -364,10 +662,11 @@ fn from_enum_internal(input: TokenStream2) -> Result<TokenStream2, Error> {
                             }
                         });
 
-                        let example_conversion_cfg = conversion_cfgs.first().unwrap();
-
-                        let args = example_conversion_cfg.to_wrapped_args(|arg| quote! { #arg });
-                        let trailer = if use_try_from {
+                        let pattern = example_conversion_cfg.to_src_pattern(src_name);
+                        let guard = example_conversion_cfg.to_guard();
+                        let trailer = if use_try_from
+                            && conversion_cfgs.iter().all(ConversionCfg::has_bound_fields)
+                        {
                             quote! {
                                 unreachable!();
                             }
@@ -376,47 +675,643 @@ fn from_enum_internal(input: TokenStream2) -> Result<TokenStream2, Error> {
                         };
 
                         quote! {
-                            #src_name::#case #args => {
+                            #pattern #guard => {
                                 #(#conversions)*
                                 #trailer
                             }
                         }
                     });
-                let dest = effect_holder_name
+                let dest_type = effect_holder_name
                     .map(|effect_holder| quote! { #effect_holder<#dest> })
                     .unwrap_or_else(|| quote! { #dest });
+                let via_arm = parser.via_by_src.get(src_name).map(|via| {
+                    let via_value = hygienic("via_value");
+                    let via_effects = hygienic("via_effects");
+                    // the first hop is the intermediate's own conversion, which we can only assume
+                    // to match ours, while the second hop is the conversion we generate from the
+                    // intermediate, if we convert from it at all
+                    let via_settings = if parser.src_order.contains(via) {
+                        parser.settings(via)
+                    } else {
+                        settings
+                    };
+                    let hop = |from: TokenStream2, to: TokenStream2, conversion_fn: Option<&ConversionFn>, conversion_trait: Option<&ConversionTrait>| {
+                        match (conversion_fn, context, conversion_trait) {
+                            (Some(ConversionFn { name, .. }), Some(_), _) => quote! { #dest::#name(#from, #ctx) },
+                            (Some(ConversionFn { name, .. }), None, _) => quote! { #dest::#name(#from) },
+                            (None, Some(_), _) => quote! { <#to as enum_to_enum::FromWith<_, _>>::from_with(#from, #ctx) },
+                            (None, None, Some(ConversionTrait { path, method })) => quote! { <#to as #path<_>>::#method(#from) },
+                            (None, None, None) => quote! { <#to as std::convert::From<_>>::from(#from) },
+                        }
+                    };
+                    let first_hop = |from: TokenStream2, to: TokenStream2| hop(from, to, None, conversion_trait);
+                    let second_hop = |from: TokenStream2, to: TokenStream2| {
+                        hop(from, to, via_settings.conversion_fn.as_ref(), via_settings.conversion_trait.as_ref())
+                    };
+                    let converted = match effect_holder_name {
+                        Some(n) => {
+                            let first = first_hop(quote! { #src }, quote! { #n<#via> });
+                            let second = second_hop(quote! { #via_value }, quote! { #n<#dest> });
+                            let composed = ok(quote_spanned! {n.span()=>
+                                #n::compose_from(#value, #via_effects.chain(#effects).collect::<Vec<_>>().into_boxed_slice())
+                            });
+                            quote_spanned! {n.span()=>
+                                let (#via_value, #via_effects) = #first.into_value_and_effects();
+                                let (#value, #effects) = #second.into_value_and_effects();
+                                #composed
+                            }
+                        }
+                        None => {
+                            let first = first_hop(quote! { #src }, quote! { #via });
+                            let second = ok(second_hop(quote! { #via_value }, quote! { #dest }));
+                            quote! {
+                                let #via_value = #first;
+                                #second
+                            }
+                        }
+                    };
+
+                    quote! {
+                        #[allow(unreachable_patterns)]
+                        #src => {
+                            #converted
+                        }
+                    }
+                });
+                let cases = cases.chain(via_arm);
 
-                quote! {
-                    impl std::convert::From<#src_name> for #dest {
-                        fn from(src: #src_name) -> #dest {
-                            use std::convert::Into;
-                            use std::convert::TryInto;
+                match (conversion_fn, context, conversion_trait) {
+                    (Some(ConversionFn { name, vis }), _, _) => {
+                        let ctx_param = context.map(|context| quote! { , #ctx: &mut #context });
 
-                            match src {
-                                #(#cases),*
+                        quote! {
+                            impl #dest {
+                                #[allow(unused_variables)]
+                                #vis fn #name(#src: #src_name #ctx_param) -> #dest_type {
+                                    use std::convert::Into;
+                                    use std::convert::TryInto;
+
+                                    match #src {
+                                        #(#cases),*
+                                    }
+                                }
                             }
                         }
                     }
+                    (None, Some(context), _) => quote! {
+                            impl enum_to_enum::FromEnumWith<#src_name, #context> for #dest_type {
+                                #[allow(unused_variables)]
+                                fn from_with(#src: #src_name, #ctx: &mut #context) -> #dest_type {
+                                    match #src {
+                                        #(#cases),*
+                                    }
+                                }
+                            }
+
+                            impl enum_to_enum::FromWith<#src_name, #context> for #dest_type {
+                                fn from_with(#src: #src_name, #ctx: &mut #context) -> #dest_type {
+                                    <#dest_type as enum_to_enum::FromEnumWith<#src_name, #context>>::from_with(#src, #ctx)
+                                }
+                            }
+                    },
+                    (None, None, Some(ConversionTrait { path, method })) => quote! {
+                            impl #path<#src_name> for #dest_type {
+                                fn #method(#src: #src_name) -> #dest_type {
+                                    use std::convert::TryInto;
+
+                                    match #src {
+                                        #(#cases),*
+                                    }
+                                }
+                            }
+                    },
+                    (None, None, None) if partial => quote! {
+                            impl std::convert::TryFrom<#src_name> for #dest_type {
+                                type Error = #src_name;
+
+                                #[allow(unreachable_patterns)]
+                                fn try_from(#src: #src_name) -> std::result::Result<#dest_type, #src_name> {
+                                    use std::convert::Into;
+                                    use std::convert::TryInto;
+
+                                    match #src {
+                                        #(#cases,)*
+                                        #src => std::result::Result::Err(#src),
+                                    }
+                                }
+                            }
+                    },
+                    (None, None, None) => quote! {
+                            impl std::convert::From<#src_name> for #dest_type {
+                                fn from(#src: #src_name) -> #dest_type {
+                                    use std::convert::Into;
+                                    use std::convert::TryInto;
+
+                                    match #src {
+                                        #(#cases),*
+                                    }
+                                }
+                            }
+                    },
                 }
             });
 
-    let effect_guard = effect_holder_name
+    let effect_guards = parser.effect_holder_names().into_iter().map(|n| {
+        quote_spanned! {n.span()=>
+            const _: () = {
+                fn assert_implements_with_effects<T: enum_to_enum::WithEffects>() {}
+
+                fn assert_impls<V>() {
+                    assert_implements_with_effects::<#n<V>>();
+                }
+            };
+        }
+    });
+
+    let remote_impl = parser
+        .remote
+        .as_ref()
+        .map(|remote| {
+            remote_impl(
+                dest,
+                remote,
+                conversion_arms_by_src
+                    .get(remote)
+                    .map(Vec::as_slice)
+                    .unwrap_or_default(),
+            )
+        })
+        .transpose()?;
+
+    let migration_impl = parser.migration.as_ref().map(|step| {
+        migration_impl(
+            dest,
+            &parser.vis,
+            step.previous.as_ref(),
+            &step.settings.effect_holder_name.as_ref(),
+        )
+    });
+
+    let deserialize_impl = if parser.deserialize {
+        Some(deserialize_impl(&parser.item, &parser.src_order))
+    } else {
+        None
+    };
+
+    Ok(quote! {
+        #(#warnings)*
+
+        #(#effect_guards)*
+
+        #(#impls)*
+
+        #remote_impl
+
+        #migration_impl
+
+        #deserialize_impl
+    })
+}
+
+/// Emits a compiler warning at `span`. Stable proc macros cannot emit warnings directly, so we use a
+/// deprecated constant, whose note is reported wherever it is used.
+fn warning(span: Span, message: &str) -> TokenStream2 {
+    quote_spanned! {span=>
+        const _: () = {
+            #[deprecated(note = #message)]
+            #[allow(non_upper_case_globals)]
+            const enum_to_enum_lint: () = ();
+            enum_to_enum_lint
+        };
+    }
+}
+
+/// Returns `ident` reported at `span`, so that errors in the conversion of a binding point at the
+/// destination field it flows into.
+fn respanned(ident: &Ident, span: Span) -> Ident {
+    let mut ident = ident.clone();
+    ident.set_span(span);
+    ident
+}
+
+/// Generates the `Versioned` enum of a migration, holding a value of this version or of any previous
+/// version, and the corresponding `Migration` and `Versioned` implementations.
+fn migration_impl(
+    dest: &Ident,
+    vis: &Visibility,
+    previous: Option<&Path>,
+    effect_holder_name: &Option<&Path>,
+) -> TokenStream2 {
+    let versioned = format_ident!("{}Versioned", dest);
+    let value = hygienic("value");
+    let effects = hygienic("effects");
+    let previous_value = hygienic("previous");
+    let previous_effects = hygienic("previous_effects");
+    let other = hygienic("other");
+    let older = hygienic("older");
+    let f = hygienic("f");
+    let output = effect_holder_name
+        .map(|n| quote! { #n<#dest> })
+        .unwrap_or_else(|| quote! { #dest });
+    let latest = effect_holder_name
         .map(|n| {
             quote_spanned! {n.span()=>
-                const _: () = {
-                    fn assert_implements_with_effects<T: enum_to_enum::WithEffects>() {}
+                <#n<#dest> as enum_to_enum::WithEffects>::new(#value, std::vec::Vec::new())
+            }
+        })
+        .unwrap_or_else(|| quote! { #value });
 
-                    fn assert_impls<V>() {
-                        assert_implements_with_effects::<#n<V>>();
+    let (
+        version,
+        previous_variant,
+        previous_version,
+        previous_migration,
+        previous_debug,
+        previous_clone,
+        previous_eq,
+        previous_bound,
+        previous_from,
+    ) = match previous {
+        Some(previous) => {
+            let migrated = effect_holder_name
+                .map(|n| {
+                    quote_spanned! {n.span()=>
+                        let (#previous_value, #previous_effects) = enum_to_enum::WithEffects::into_value_and_effects(
+                            enum_to_enum::Versioned::migrate_to_latest(#previous_value),
+                        );
+                        let (#value, #effects) = enum_to_enum::WithEffects::into_value_and_effects(
+                            <#n<#dest> as std::convert::From<#previous>>::from(#previous_value),
+                        );
+                        <#n<#dest> as enum_to_enum::WithEffects>::compose_from(
+                            #value,
+                            #previous_effects.chain(#effects).collect::<std::vec::Vec<_>>().into_boxed_slice(),
+                        )
                     }
-                };
+                })
+                .unwrap_or_else(|| {
+                    quote! {
+                        <#dest as std::convert::From<#previous>>::from(
+                            enum_to_enum::Versioned::migrate_to_latest(#previous_value),
+                        )
+                    }
+                });
+
+            (
+                quote! { <#previous as enum_to_enum::Migration>::VERSION + 1 },
+                Some(quote! {
+                    Previous(<#previous as enum_to_enum::Migration>::Versioned),
+                }),
+                Some(quote! {
+                    Self::Previous(#previous_value) => enum_to_enum::Versioned::version(#previous_value),
+                }),
+                Some(quote! {
+                    Self::Previous(#previous_value) => {
+                        #migrated
+                    }
+                }),
+                Some(quote! {
+                    Self::Previous(#previous_value) => #f.debug_tuple("Previous").field(#previous_value).finish(),
+                }),
+                Some(quote! {
+                    Self::Previous(#previous_value) => Self::Previous(std::clone::Clone::clone(#previous_value)),
+                }),
+                Some(quote! {
+                    (Self::Previous(#previous_value), Self::Previous(#other)) => #previous_value == #other,
+                }),
+                Some(quote! { <#previous as enum_to_enum::Migration>::Versioned }),
+                Some(quote! {
+                    // any older version converts into the Versioned enum of the previous version,
+                    // which we hold in our Previous variant
+                    impl<EnumToEnumOlder: enum_to_enum::Migration> std::convert::From<EnumToEnumOlder> for #versioned
+                    where
+                        <#previous as enum_to_enum::Migration>::Versioned: std::convert::From<EnumToEnumOlder>,
+                    {
+                        fn from(#older: EnumToEnumOlder) -> #versioned {
+                            #versioned::Previous(std::convert::From::from(#older))
+                        }
+                    }
+                }),
+            )
+        }
+        None => (quote! { 1 }, None, None, None, None, None, None, None, None),
+    };
+
+    // forward the common traits only when every version implements them; a bound on a concrete type
+    // that does not hold merely leaves the impl unusable, as long as it is quantified over a lifetime
+    let forward_bounds = |trait_path: TokenStream2| {
+        let previous_bound = previous_bound.iter();
+        quote! {
+            where
+                for<'a> #dest: #trait_path,
+                #(for<'a> #previous_bound: #trait_path,)*
+        }
+    };
+    let debug_bounds = forward_bounds(quote! { std::fmt::Debug });
+    let clone_bounds = forward_bounds(quote! { std::clone::Clone });
+    let partial_eq_bounds = forward_bounds(quote! { std::cmp::PartialEq });
+    let eq_bounds = forward_bounds(quote! { std::cmp::Eq });
+    let dest_name = dest.to_string();
+    let mismatch = previous.map(|_| quote! { _ => false, });
+
+    quote! {
+        #vis enum #versioned {
+            #dest(#dest),
+            #previous_variant
+        }
+
+        impl enum_to_enum::Migration for #dest {
+            const VERSION: u32 = #version;
+            type Versioned = #versioned;
+        }
+
+        impl enum_to_enum::Versioned for #versioned {
+            type Output = #output;
+
+            fn version(&self) -> u32 {
+                match self {
+                    Self::#dest(_) => <#dest as enum_to_enum::Migration>::VERSION,
+                    #previous_version
+                }
+            }
+
+            fn migrate_to_latest(self) -> #output {
+                match self {
+                    Self::#dest(#value) => #latest,
+                    #previous_migration
+                }
+            }
+        }
+
+        impl std::convert::From<#dest> for #versioned {
+            fn from(#value: #dest) -> #versioned {
+                #versioned::#dest(#value)
+            }
+        }
+
+        #previous_from
+
+        impl std::fmt::Debug for #versioned #debug_bounds {
+            fn fmt(&self, #f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    Self::#dest(#value) => #f.debug_tuple(#dest_name).field(#value).finish(),
+                    #previous_debug
+                }
+            }
+        }
+
+        impl std::clone::Clone for #versioned #clone_bounds {
+            fn clone(&self) -> Self {
+                match self {
+                    Self::#dest(#value) => Self::#dest(std::clone::Clone::clone(#value)),
+                    #previous_clone
+                }
+            }
+        }
+
+        impl std::cmp::PartialEq for #versioned #partial_eq_bounds {
+            fn eq(&self, #other: &Self) -> bool {
+                match (self, #other) {
+                    (Self::#dest(#value), Self::#dest(#other)) => #value == #other,
+                    #previous_eq
+                    #mismatch
+                }
+            }
+        }
+
+        impl std::cmp::Eq for #versioned #eq_bounds {}
+    }
+}
+
+/// Generates the arm for bare `#[from_case(flatten)]` variants, which tries each nested enum in turn,
+/// handing the source enum back to the next one on failure.
+fn flatten_fallback_arm<W: Fn(TokenStream2, &ConversionCfg, bool) -> TokenStream2>(
+    dest: &Ident,
+    src_name: &Path,
+    conversion_cfgs: &[ConversionCfg],
+    effect_holder_name: &Option<&Path>,
+    result_wrapper: &W,
+) -> TokenStream2 {
+    let src = hygienic("src");
+    let attempts = conversion_cfgs.iter().map(|conversion_cfg| {
+        let case_match = conversion_cfg.to_case_match(dest, src_name, effect_holder_name.is_some());
+        let res = result_wrapper(case_match, conversion_cfg, true);
+        let converted = conversion_cfg.each_arg(|_, ty| {
+            let full_type = effect_holder_name
+                .map(|n| quote! { #n<#ty> })
+                .unwrap_or_else(|| quote! { #ty });
+
+            quote! {
+                <#full_type as std::convert::TryFrom<#src_name>>::try_from(#src)
+            }
+        });
+        let args = conversion_cfg.to_args(|arg, _| quote! { #arg });
+
+        quote! {
+            let #src = match #(#converted)* {
+                std::result::Result::Ok(#args) => {
+                    #res;
+                }
+                std::result::Result::Err(#src) => #src,
+            };
+        }
+    });
+
+    // the parser only accepts a bare flatten in a partial conversion, which hands back the source
+    // variant that no flattened enum accepts
+    quote! {
+        #src => {
+            #(#attempts)*
+            std::result::Result::Err(#src)
+        }
+    }
+}
+
+/// Generates a `Deserialize` implementation that tries the destination's own format, through a
+/// shadow enum with the same variants and serde attributes, and then each source enum in declaration
+/// order, converting with the generated `From`.
+fn deserialize_impl(item: &ItemEnum, srcs: &[Path]) -> TokenStream2 {
+    fn is_serde_attr(attr: &Attribute) -> bool {
+        attr.path.is_ident("serde")
+    }
+
+    let dest = &item.ident;
+    let dest_name = dest.to_string();
+    let shadow_name = format_ident!("{}Shadow", dest);
+
+    let mut shadow = item.clone();
+    shadow.ident = shadow_name.clone();
+    shadow.vis = Visibility::Inherited;
+    shadow.attrs.retain(is_serde_attr);
+    // only a rename key, like #[serde(rename = "..")] or #[serde(rename(deserialize = ".."))],
+    // renames the enum; #[serde(tag = "rename")] does not
+    let has_rename = shadow.attrs.iter().any(|attr| match attr.parse_meta() {
+        Ok(Meta::List(list)) => list.nested.iter().any(|nested| match nested {
+            NestedMeta::Meta(meta) => meta.path().is_ident("rename"),
+            NestedMeta::Lit(_) => false,
+        }),
+        _ => false,
+    });
+    if !has_rename {
+        shadow
+            .attrs
+            .push(parse_quote! { #[serde(rename = #dest_name)] });
+    }
+    shadow.variants.iter_mut().for_each(|variant| {
+        variant.attrs.retain(is_serde_attr);
+        variant
+            .fields
+            .iter_mut()
+            .for_each(|field| field.attrs.retain(is_serde_attr));
+    });
+
+    let value = hygienic("value");
+    let errors = hygienic("errors");
+    let err = hygienic("err");
+    let src = hygienic("src");
+    let shadow_value = hygienic("shadow");
+    let deserializer = hygienic("deserializer");
+
+    let arms = item.variants.iter().map(|variant| {
+        let case = &variant.ident;
+        match &variant.fields {
+            Fields::Unit => quote! { #shadow_name::#case => #dest::#case },
+            Fields::Named(fields) => {
+                let names = fields.named.iter().map(|field| &field.ident);
+                let names2 = names.clone();
+                quote! { #shadow_name::#case { #(#names),* } => #dest::#case { #(#names2),* } }
+            }
+            Fields::Unnamed(fields) => {
+                let args = (0..fields.unnamed.len())
+                    .map(|i| format_ident!("arg{}", i, span = Span::mixed_site()))
+                    .collect::<Vec<_>>();
+                quote! { #shadow_name::#case(#(#args),*) => #dest::#case(#(#args),*) }
+            }
+        }
+    });
+
+    let attempts = srcs.iter().map(|src_type| {
+        let src_name = src_type.display_path();
+
+        quote! {
+            match #value.clone().deserialize_into::<#src_type>() {
+                std::result::Result::Ok(#src) => {
+                    return std::result::Result::Ok(<#dest as std::convert::From<#src_type>>::from(#src));
+                }
+                std::result::Result::Err(#err) => #errors.push(format!("as {}: {}", #src_name, #err)),
+            }
+        }
+    });
+
+    quote! {
+        const _: () = {
+            use enum_to_enum::__private::{serde, serde_value};
+
+            #[derive(serde::Deserialize)]
+            #[serde(crate = "enum_to_enum::__private::serde")]
+            #shadow
+
+            impl std::convert::From<#shadow_name> for #dest {
+                fn from(#src: #shadow_name) -> #dest {
+                    match #src {
+                        #(#arms),*
+                    }
+                }
+            }
+
+            impl<'de> serde::Deserialize<'de> for #dest {
+                fn deserialize<D>(#deserializer: D) -> std::result::Result<#dest, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {
+                    let #value = <serde_value::Value as serde::Deserialize>::deserialize(#deserializer)?;
+                    let mut #errors = std::vec::Vec::new();
+
+                    match #value.clone().deserialize_into::<#shadow_name>() {
+                        std::result::Result::Ok(#shadow_value) => {
+                            return std::result::Result::Ok(<#dest as std::convert::From<#shadow_name>>::from(#shadow_value));
+                        }
+                        std::result::Result::Err(#err) => #errors.push(format!("as {}: {}", #dest_name, #err)),
+                    }
+
+                    #(#attempts)*
+
+                    std::result::Result::Err(<D::Error as serde::de::Error>::custom(format!(
+                        "data did not match {} or any of its source enums ({})",
+                        #dest_name,
+                        #errors.join("; "),
+                    )))
+                }
+            }
+        };
+    }
+}
+
+/// Generates the reverse conversion, from our mirror of a remote enum back into the remote enum.
+fn remote_impl(
+    dest: &Ident,
+    remote: &Path,
+    conversion_arms: &[Vec<ConversionCfg>],
+) -> Result<TokenStream2, Error> {
+    let mut errors: Vec<Error> = vec![];
+    let mut seen_dests = HashSet::new();
+    let cases = conversion_arms
+        .iter()
+        .filter_map(|conversion_cfgs| match conversion_cfgs.as_slice() {
+            [conversion_cfg] => {
+                if !seen_dests.insert(&conversion_cfg.dest.ident) {
+                    errors.push(
+                        SynError::new(
+                            conversion_cfg.dest.ident.span(),
+                            "a remote mirror variant may only correspond to a single remote variant",
+                        )
+                        .into(),
+                    );
+                }
+
+                let dest_pattern = conversion_cfg.to_dest_pattern(dest);
+                let remote_case = conversion_cfg.to_reverse_case_match(remote);
+                if remote_case.is_none() {
+                    errors.push(
+                        SynError::new(
+                            conversion_cfg.dest.ident.span(),
+                            "a remote mirror variant may only use plain from_case variants, without patterns, guards or wildcards",
+                        )
+                        .into(),
+                    );
+                }
+
+                Some(quote! {
+                    #dest_pattern => #remote_case
+                })
+            }
+            _ => {
+                conversion_cfgs.iter().for_each(|conversion_cfg| {
+                    errors.push(
+                        SynError::new(
+                            conversion_cfg.dest.ident.span(),
+                            "a remote variant may only correspond to a single mirror variant",
+                        )
+                        .into(),
+                    );
+                });
+                None
             }
         })
-        .unwrap_or_else(|| quote! {});
+        .collect::<Vec<_>>();
+
+    if !errors.is_empty() {
+        return Err(errors.into());
+    }
 
     Ok(quote! {
-        #effect_guard
+        impl std::convert::From<#dest> for #remote {
+            fn from(src: #dest) -> #remote {
+                use std::convert::Into;
 
-        #(#impls)*
+                match src {
+                    #(#cases),*
+                }
+            }
+        }
     })
 }
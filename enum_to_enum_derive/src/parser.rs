@@ -1,93 +1,397 @@
 use crate::error::Error;
-use crate::ir::{ConversionCfg, SrcCase, SrcCasesBySrc, SrcEnum};
+use crate::ir::{
+    CaseName, CasePattern, ConversionCfg, ConversionFn, ConversionSettings, ConversionTrait,
+    FieldSource, MigrationStep, SrcCase, SrcCasesBySrc, SrcEnum,
+};
 use crate::merge_in::MergeIn;
-use crate::syn_extensions::MatchesIdent;
-use proc_macro2::TokenStream as TokenStream2;
+use crate::rename::{parse_affix, NameRules, RenameRule};
+use crate::suggest::did_you_mean;
+use crate::syn_extensions::{Bindings, DisplayPath, MatchesIdent};
+use proc_macro2::{TokenStream as TokenStream2, TokenTree};
+use quote::{format_ident, quote};
+use std::cmp::Reverse;
 use std::collections::{HashMap, HashSet};
 use syn::{
+    ext::IdentExt,
     parenthesized,
     parse::{Error as ParseError, Parse, ParseStream, Result as ParseResult},
-    parse2,
-    punctuated::Punctuated,
+    parse2, parse_quote,
     spanned::Spanned,
-    token::{Comma as CommaToken, Eq as EqToken},
+    token::{Brace, Comma as CommaToken, Eq as EqToken, Paren},
     visit::{visit_item_enum, Visit},
-    Attribute, Ident, ItemEnum, Path, Token, Variant,
+    Attribute, Fields, Ident, ItemEnum, LitInt, LitStr, Pat, Path, Token, Type, Variant,
+    Visibility,
 };
 
 #[derive(Debug)]
 pub struct ParsedEnum {
     pub dest: Ident,
-    pub effect_holder_name: Option<Path>,
+    pub remote: Option<Path>,
+    pub via_by_src: HashMap<Path, Path>,
+    /// The migration step declared by a from_enum attribute, if any.
+    pub migration: Option<MigrationStep>,
+    pub vis: Visibility,
+    pub deserialize: bool,
+    /// Whether lints are reported as errors rather than warnings.
+    pub strict: bool,
+    /// The source enums, in the order in which they were declared.
+    pub src_order: Vec<Path>,
+    /// The annotated enum itself.
+    pub item: ItemEnum,
     src_names: HashSet<Path>,
+    settings_by_src: HashMap<Path, ConversionSettings>,
     src_cases_by_src_by_dest: HashMap<Variant, SrcCasesBySrc>,
+    /// The source enums that each destination variant excludes with `except(..)`.
+    excluded_srcs_by_dest: HashMap<Variant, Vec<Path>>,
+    /// The destination variants marked with `skip`, which no source enum converts into.
+    skipped_dests: HashSet<Variant>,
+    field_sources_by_dest: HashMap<Variant, Vec<FieldSource>>,
     dest_case_order: HashMap<Variant, usize>,
 }
 
 impl ParsedEnum {
-    pub fn conversion_cfgs_by_src_case_by_src(
-        &self,
-    ) -> HashMap<Path, HashMap<Ident, Vec<ConversionCfg>>> {
+    /// Returns the settings of the conversion from `src`.
+    pub fn settings(&self, src: &Path) -> &ConversionSettings {
+        &self.settings_by_src[src]
+    }
+
+    /// Returns each distinct effect container, in declaration order.
+    pub fn effect_holder_names(&self) -> Vec<&Path> {
+        let mut effect_holder_names: Vec<&Path> = vec![];
+        self.src_order
+            .iter()
+            .filter_map(|src| self.settings(src).effect_holder_name.as_ref())
+            .chain(
+                self.migration
+                    .iter()
+                    .filter_map(|step| step.settings.effect_holder_name.as_ref()),
+            )
+            .for_each(|effect_holder_name| {
+                if !effect_holder_names.contains(&effect_holder_name) {
+                    effect_holder_names.push(effect_holder_name);
+                }
+            });
+        effect_holder_names
+    }
+
+    /// Returns, for each source enum, the match arms of its conversion in the order in which they
+    /// must be emitted. Each arm holds the conversion candidates to try, in order.
+    /// Arms with explicit patterns or guards come first, in declaration order, followed by arms for
+    /// plain source cases and, finally, any wildcard arm or the arm trying each flattened enum.
+    pub fn conversion_arms_by_src(&self) -> HashMap<Path, Vec<Vec<ConversionCfg>>> {
         let src_names = &self.src_names;
 
-        let conversion_cfgs_by_src_case_by_src = self.src_cases_by_src_by_dest.iter().fold(
-            HashMap::new(),
-            |mut conversion_cfgs_by_src_case_by_src, (dest, src_cases_by_src)| {
+        let mut conversion_cfgs_by_src = self.src_cases_by_src_by_dest.iter().fold(
+            src_names
+                .iter()
+                .map(|src| (src.clone(), vec![]))
+                .collect::<HashMap<_, _>>(),
+            |mut conversion_cfgs_by_src, (dest, src_cases_by_src)| {
+                // cases for all source enums only apply to those without cases of their own, that
+                // are not converted via an intermediate enum and that the variant does not exclude
+                let excluded_srcs = self.excluded_srcs_by_dest.get(dest);
+                let other_src_names = src_names
+                    .iter()
+                    .filter(|src| !self.via_by_src.contains_key(*src))
+                    .filter(|src| !excluded_srcs.is_some_and(|excluded| excluded.contains(*src)))
+                    .filter(|src| !src_cases_by_src.contains_key(&SrcEnum::Single((*src).clone())))
+                    .collect::<Vec<_>>();
                 src_cases_by_src.iter().for_each(|(src, src_cases)| {
-                    let mut m: HashMap<Path, HashMap<Ident, Vec<ConversionCfg>>> = HashMap::new();
-                    let conversion_cfgs_by_src_case = src_cases
+                    let mut m: HashMap<Path, Vec<ConversionCfg>> = HashMap::new();
+                    let conversion_cfgs = src_cases
                         .iter()
-                        .map(|src_case| {
-                            (
-                                src_case.case_name.clone(),
-                                vec![ConversionCfg {
-                                    src_case: src_case.clone(),
-                                    dest: dest.clone(),
-                                }],
-                            )
+                        .map(|src_case| ConversionCfg {
+                            src_case: src_case.clone(),
+                            dest: dest.clone(),
+                            field_sources: self.field_sources_by_dest[dest].clone(),
                         })
-                        .collect();
+                        .collect::<Vec<_>>();
 
                     match src {
                         SrcEnum::Single(src) => {
-                            m.insert(src.clone(), conversion_cfgs_by_src_case);
+                            m.insert(src.clone(), conversion_cfgs);
                         }
                         SrcEnum::All() => {
-                            for src in src_names {
-                                m.insert(src.clone(), conversion_cfgs_by_src_case.clone());
+                            for src in &other_src_names {
+                                m.insert((*src).clone(), conversion_cfgs.clone());
                             }
                         }
                     };
-                    conversion_cfgs_by_src_case_by_src.merge_in(m);
+                    conversion_cfgs_by_src.merge_in(m);
                 });
-                conversion_cfgs_by_src_case_by_src
+                conversion_cfgs_by_src
             },
         );
-        conversion_cfgs_by_src_case_by_src
-            .into_iter()
-            .map(|(src, conversion_cfgs_by_src_case)| {
-                (
-                    src,
-                    conversion_cfgs_by_src_case
-                        .into_iter()
-                        .map(|(src_case, mut conversion_cfgs)| {
-                            conversion_cfgs.sort_by_key(|cfg| {
-                                self.dest_case_order.get(&cfg.dest).unwrap_or(&0)
-                            });
-                            (src_case, conversion_cfgs)
-                        })
-                        .collect(),
-                )
+
+        conversion_cfgs_by_src
+            .drain()
+            .map(|(src, mut conversion_cfgs)| {
+                conversion_cfgs
+                    .sort_by_key(|cfg| self.dest_case_order.get(&cfg.dest).unwrap_or(&0));
+                // higher priorities first, then unprioritized candidates in declaration order
+                conversion_cfgs.sort_by_key(|cfg| {
+                    let priority = cfg.src_case.priority();
+                    (priority.is_none(), Reverse(priority))
+                });
+
+                let mut pattern_arms = vec![];
+                let mut case_arms: Vec<Vec<ConversionCfg>> = vec![];
+                let mut wildcard_arm = vec![];
+                conversion_cfgs.into_iter().for_each(|cfg| {
+                    if cfg.src_case.pattern.is_some() || cfg.src_case.guard.is_some() {
+                        pattern_arms.push(vec![cfg]);
+                    } else if cfg.src_case.case_name == CaseName::Wildcard() {
+                        // a wildcard arm never coexists with a bare flatten arm
+                        wildcard_arm.push(cfg);
+                    } else if cfg.src_case.flatten {
+                        case_arms.push(vec![cfg]);
+                    } else if let Some(arm) = case_arms.iter_mut().find(|arm| {
+                        !arm[0].src_case.flatten
+                            && arm[0].src_case.case_name == cfg.src_case.case_name
+                    }) {
+                        arm.push(cfg);
+                    } else {
+                        case_arms.push(vec![cfg]);
+                    }
+                });
+
+                let mut arms = pattern_arms;
+                arms.extend(case_arms);
+                if !wildcard_arm.is_empty() {
+                    arms.push(wildcard_arm);
+                }
+
+                (src, arms)
+            })
+            .collect()
+    }
+
+    /// Returns an error for each flatten that applies to a source enum without `partial`, whose
+    /// conversion would have no way to report a source variant that the flattened enums do not
+    /// accept.
+    fn check_flattens(&self) -> Vec<Error> {
+        let arms_by_src = self.conversion_arms_by_src();
+
+        self.src_order
+            .iter()
+            .filter(|src| !self.settings(src).partial)
+            .flat_map(|src| {
+                arms_by_src[src]
+                    .iter()
+                    .filter(|arm| arm[0].src_case.flatten)
+                    .flatten()
+                    .map(move |cfg| {
+                        let message = if cfg.src_case.case_name == CaseName::Wildcard() {
+                            format!(
+                                "#[from_case(flatten)] without a list of cases requires partial on the from_enum of {}, to hand back the source variants that no flattened enum accepts",
+                                src.display_path(),
+                            )
+                        } else {
+                            format!(
+                                "#[from_case(flatten(..))] requires partial on the from_enum of {}, to hand back the listed source variants that the flattened enum does not accept",
+                                src.display_path(),
+                            )
+                        };
+                        ParseError::new(cfg.dest.ident.span(), message).into()
+                    })
             })
             .collect()
     }
+
+    /// Returns an error for each candidate for a source case that a `flatten(..)` also lists. The
+    /// flattened enum takes the whole source variant, so whichever of their arms comes second would
+    /// never be reached.
+    fn check_flattened_cases(&self) -> Vec<Error> {
+        let arms_by_src = self.conversion_arms_by_src();
+        let mut errors = vec![];
+
+        self.src_order.iter().for_each(|src| {
+            let mut seen = HashSet::new();
+            arms_by_src[src]
+                .iter()
+                .filter(|arm| arm[0].src_case.pattern.is_none() && arm[0].src_case.guard.is_none())
+                .filter_map(|arm| match &arm[0].src_case.case_name {
+                    CaseName::Variant(case) => Some((case, arm)),
+                    CaseName::Wildcard() => None,
+                })
+                .filter(|(case, _)| !seen.insert(*case))
+                .for_each(|(case, arm)| {
+                    errors.extend(arm.iter().map(|cfg| {
+                        ParseError::new(
+                            cfg.dest.ident.span(),
+                            format!(
+                                "{}::{} is listed by #[from_case(flatten(..))] and by another from_case, but the flattened enum takes the whole source variant, so only one variant may convert from it",
+                                src.display_path(),
+                                case,
+                            ),
+                        )
+                        .into()
+                    }));
+                });
+        });
+
+        errors
+    }
+
+    /// Returns an error for each candidate with the same explicit priority as another candidate in
+    /// the same match arm. Candidates with patterns or guards have arms of their own, which may well
+    /// be disjoint, so only candidates for the same plain source case are compared.
+    fn check_priorities(&self) -> Vec<Error> {
+        let mut reported = HashSet::new();
+        let mut errors = vec![];
+
+        self.conversion_arms_by_src()
+            .values()
+            .flatten()
+            .for_each(|arm| {
+                let mut seen: HashMap<(&CaseName, u32), &ConversionCfg> = HashMap::new();
+                arm.iter().for_each(|cfg| {
+                    let (priority, value) = match (&cfg.src_case.priority, cfg.src_case.priority())
+                    {
+                        (Some(priority), Some(value)) => (priority, value),
+                        _ => return,
+                    };
+                    let case_name = &cfg.src_case.case_name;
+                    match seen.get(&(case_name, value)) {
+                        Some(other) => {
+                            let conflict = (other.dest.ident.clone(), cfg.dest.ident.clone());
+                            if reported.insert(conflict) {
+                                let case_name = case_name_string(case_name);
+                                errors.push(
+                                    ParseError::new(
+                                        priority.span(),
+                                        format!(
+                                            "{} and {} both have priority {} for source case {}",
+                                            other.dest.ident, cfg.dest.ident, value, case_name,
+                                        ),
+                                    )
+                                    .into(),
+                                );
+                            }
+                        }
+                        None => {
+                            seen.insert((case_name, value), cfg);
+                        }
+                    }
+                });
+            });
+
+        errors
+    }
+
+    /// Returns a lint for each likely mistake that still compiles: candidates that can never be
+    /// reached because an earlier candidate for the same source case is marked infallible, source
+    /// cases listed more than once for the same destination variant and destination variants that
+    /// no source variant converts into.
+    pub fn lints(&self) -> Vec<ParseError> {
+        let mut lints = vec![];
+        let conversion_arms_by_src = self.conversion_arms_by_src();
+
+        let mut reported = HashSet::new();
+        conversion_arms_by_src.values().flatten().for_each(|arm| {
+            let earlier = match arm.iter().position(|cfg| {
+                cfg.src_case.infallible.is_some() || !cfg.has_bound_fields()
+            }) {
+                Some(i) => &arm[i],
+                None => return,
+            };
+            let reason = if earlier.has_bound_fields() {
+                "is marked infallible"
+            } else {
+                "has no fields to try_from"
+            };
+            arm.iter()
+                .skip_while(|cfg| !std::ptr::eq(*cfg, earlier))
+                .skip(1)
+                .filter(|cfg| reported.insert((cfg.dest.ident.clone(), cfg.src_case.case_name.clone())))
+                .for_each(|cfg| {
+                    lints.push(ParseError::new(
+                        cfg.dest.ident.span(),
+                        format!(
+                            "{} is unreachable for source case {}, because {} {} and is tried first",
+                            cfg.dest.ident,
+                            case_name_string(&cfg.src_case.case_name),
+                            earlier.dest.ident,
+                            reason,
+                        ),
+                    ));
+                });
+        });
+
+        let mut dests = self.src_cases_by_src_by_dest.iter().collect::<Vec<_>>();
+        dests.sort_by_key(|(dest, _)| self.dest_case_order.get(*dest));
+        dests.iter().for_each(|(dest, src_cases_by_src)| {
+            src_cases_by_src.values().for_each(|src_cases| {
+                let plain_cases = src_cases
+                    .iter()
+                    .filter(|src_case| src_case.pattern.is_none() && src_case.guard.is_none());
+                let mut seen = HashSet::new();
+                plain_cases
+                    .filter(|src_case| !seen.insert((&src_case.case_name, src_case.flatten)))
+                    .for_each(|src_case| {
+                        let span = match &src_case.case_name {
+                            CaseName::Variant(case) => case.span(),
+                            CaseName::Wildcard() => dest.ident.span(),
+                        };
+                        lints.push(ParseError::new(
+                            span,
+                            format!(
+                                "source case {} is listed more than once for {}",
+                                case_name_string(&src_case.case_name),
+                                dest.ident,
+                            ),
+                        ));
+                    });
+            });
+        });
+
+        // conversions via an intermediate enum that we do not convert from ourselves may reach any
+        // destination variant
+        let opaque_via = self
+            .via_by_src
+            .values()
+            .any(|via| !self.src_names.contains(via));
+        if !self.src_names.is_empty() && !opaque_via {
+            let reachable = conversion_arms_by_src
+                .values()
+                .flatten()
+                .flatten()
+                .map(|cfg| &cfg.dest.ident)
+                .collect::<HashSet<_>>();
+            // skipped variants are never meant to be converted into
+            dests
+                .iter()
+                .filter(|(dest, _)| !self.skipped_dests.contains(*dest))
+                .filter(|(dest, _)| !reachable.contains(&dest.ident))
+                .for_each(|(dest, _)| {
+                    lints.push(ParseError::new(
+                        dest.ident.span(),
+                        format!("no source variant converts into {}", dest.ident),
+                    ));
+                });
+        }
+
+        lints
+    }
 }
 
 #[derive(Debug, Default)]
 pub struct EnumParser {
     src_names: HashSet<Path>,
-    effect_holder_name: Option<Path>,
+    settings_by_src: HashMap<Path, ConversionSettings>,
+    remote: Option<Path>,
+    via_by_src: HashMap<Path, Path>,
+    migration: Option<MigrationStep>,
+    deserialize: Option<Ident>,
+    strict: bool,
+    /// Whether a from_enum attribute has already been parsed.
+    seen_from_enum: bool,
+    src_order: Vec<Path>,
+    name_rules_by_src: HashMap<Path, NameRules>,
     src_cases_by_src_by_dest: HashMap<Variant, SrcCasesBySrc>,
+    excluded_srcs_by_dest: HashMap<Variant, Vec<Path>>,
+    skipped_dests: HashSet<Variant>,
+    field_sources_by_dest: HashMap<Variant, Vec<FieldSource>>,
     dest_case_order: HashMap<Variant, usize>,
     errors: Vec<Error>,
 }
@@ -98,25 +402,113 @@ impl<'ast> EnumParser {
         let enm: ItemEnum = parse2(input)?;
         visit_item_enum(&mut parser, &enm);
 
+        // later checks would only report follow-on errors of malformed attributes
         if !parser.errors.is_empty() {
             return Err(parser.errors.into());
         }
 
-        if parser.src_names.is_empty() {
-            return Err(ParseError::new(
-                enm.span(),
-                "#[from_enum(Src)] must appear at least once to specify the source enum(s)",
-            )
-            .into());
+        if parser.src_names.is_empty() && parser.migration.is_none() {
+            parser.errors.push(
+                ParseError::new(
+                    enm.span(),
+                    "#[from_enum(Src)] must appear at least once to specify the source enum(s)",
+                )
+                .into(),
+            );
+        }
+
+        let (bare_flattens, wildcards): (Vec<_>, Vec<_>) = parser
+            .src_cases_by_src_by_dest
+            .values()
+            .flat_map(SrcCasesBySrc::values)
+            .flatten()
+            .filter(|src_case| src_case.case_name == CaseName::Wildcard())
+            .partition(|src_case| src_case.flatten);
+        if !bare_flattens.is_empty() && !wildcards.is_empty() {
+            parser.errors.push(
+                ParseError::new(
+                    enm.span(),
+                    "#[from_case(flatten)] may not be combined with a wildcard from_case",
+                )
+                .into(),
+            );
+        }
+
+        // the second hop of a conversion via an intermediate enum that we convert from ourselves
+        // uses that conversion, which must fit in the conversion from the original source
+        for src in &parser.src_order {
+            let (via, settings) = match parser.via_by_src.get(src) {
+                Some(via) => (via, &parser.settings_by_src[src]),
+                None => continue,
+            };
+            if let Some(via_settings) = parser.settings_by_src.get(via) {
+                if via_settings.partial
+                    || via_settings.effect_holder_name != settings.effect_holder_name
+                    || via_settings.context != settings.context
+                {
+                    parser.errors.push(
+                        ParseError::new(
+                            via.span(),
+                            format!(
+                                "via = {} requires the conversion from {} to be infallible and to use the same effect_container and context as the conversion from {}",
+                                via.display_path(),
+                                via.display_path(),
+                                src.display_path(),
+                            ),
+                        )
+                        .into(),
+                    );
+                }
+            }
+        }
+
+        if let Some(deserialize) = &parser.deserialize {
+            if parser
+                .settings_by_src
+                .values()
+                .any(|settings| *settings != ConversionSettings::default())
+            {
+                parser.errors.push(
+                    ParseError::new(
+                        deserialize.span(),
+                        "deserialize requires a plain From conversion from every source enum, so no from_enum may use effect_container, context, trait, fn or partial",
+                    )
+                    .into(),
+                );
+            }
         }
 
-        Ok(ParsedEnum {
+        if !parser.errors.is_empty() {
+            return Err(parser.errors.into());
+        }
+
+        let parsed = ParsedEnum {
             src_names: parser.src_names,
-            effect_holder_name: parser.effect_holder_name,
+            settings_by_src: parser.settings_by_src,
+            remote: parser.remote,
+            via_by_src: parser.via_by_src,
+            migration: parser.migration,
+            vis: enm.vis.clone(),
+            deserialize: parser.deserialize.is_some(),
+            strict: parser.strict,
+            src_order: parser.src_order,
             src_cases_by_src_by_dest: parser.src_cases_by_src_by_dest,
-            dest: enm.ident,
+            excluded_srcs_by_dest: parser.excluded_srcs_by_dest,
+            skipped_dests: parser.skipped_dests,
+            field_sources_by_dest: parser.field_sources_by_dest,
+            dest: enm.ident.clone(),
+            item: enm,
             dest_case_order: parser.dest_case_order,
-        })
+        };
+
+        let mut errors = parsed.check_priorities();
+        errors.extend(parsed.check_flattens());
+        errors.extend(parsed.check_flattened_cases());
+        if !errors.is_empty() {
+            return Err(errors.into());
+        }
+
+        Ok(parsed)
     }
 
     fn parse_from_enum_attr(&mut self, node: &'ast Attribute) {
@@ -124,10 +516,104 @@ impl<'ast> EnumParser {
             return;
         }
 
+        let seen_from_enum = std::mem::replace(&mut self.seen_from_enum, true);
         match parse2::<FromEnumAttr>(node.tokens.clone()) {
             Ok(from_enum_attr) => {
-                self.src_names.extend(from_enum_attr.sources);
-                self.effect_holder_name = from_enum_attr.effect;
+                // a source enum may be listed again, but only with the same settings
+                for src in from_enum_attr.sources.iter().chain(&from_enum_attr.remote) {
+                    if !self.src_names.contains(src) {
+                        continue;
+                    }
+                    let same_settings = self.settings_by_src.get(src)
+                        == Some(&from_enum_attr.settings)
+                        && self.name_rules_by_src.get(src).cloned().unwrap_or_default()
+                            == from_enum_attr.name_rules
+                        && self.via_by_src.get(src) == from_enum_attr.via.as_ref();
+                    if !same_settings {
+                        self.errors.push(
+                            ParseError::new(
+                                src.span(),
+                                format!(
+                                    "{} is already listed in another from_enum with different settings",
+                                    src.display_path(),
+                                ),
+                            )
+                            .into(),
+                        );
+                    }
+                }
+                from_enum_attr
+                    .sources
+                    .iter()
+                    .chain(&from_enum_attr.remote)
+                    .for_each(|src| {
+                        self.settings_by_src
+                            .insert(src.clone(), from_enum_attr.settings.clone());
+                    });
+                if !from_enum_attr.name_rules.is_empty() {
+                    from_enum_attr
+                        .sources
+                        .iter()
+                        .chain(&from_enum_attr.remote)
+                        .for_each(|src| {
+                            self.name_rules_by_src
+                                .insert(src.clone(), from_enum_attr.name_rules.clone());
+                        });
+                }
+                if let Some(via) = &from_enum_attr.via {
+                    from_enum_attr.sources.iter().for_each(|src| {
+                        self.via_by_src.insert(src.clone(), via.clone());
+                    });
+                }
+                for src in from_enum_attr.sources.iter().chain(&from_enum_attr.remote) {
+                    if !self.src_order.contains(src) {
+                        self.src_order.push(src.clone());
+                    }
+                }
+                self.src_names
+                    .extend(from_enum_attr.sources.iter().cloned());
+                if let Some(remote) = from_enum_attr.remote {
+                    self.src_names.insert(remote.clone());
+                    self.remote = Some(remote);
+                }
+                if let Some(migration) = &from_enum_attr.migration {
+                    if self.migration.is_some() {
+                        self.errors.push(
+                            ParseError::new(
+                                migration.span(),
+                                "migration may only be declared by one from_enum",
+                            )
+                            .into(),
+                        );
+                    }
+                    self.migration = Some(MigrationStep {
+                        previous: from_enum_attr.sources.first().cloned(),
+                        settings: from_enum_attr.settings.clone(),
+                    });
+                }
+                // these flags apply to the whole enum, so they belong in the first from_enum
+                if seen_from_enum {
+                    for flag in from_enum_attr
+                        .deserialize
+                        .iter()
+                        .chain(&from_enum_attr.strict)
+                    {
+                        self.errors.push(
+                            ParseError::new(
+                                flag.span(),
+                                format!(
+                                    "{} applies to every source enum, so only the first from_enum may include it",
+                                    flag,
+                                ),
+                            )
+                            .into(),
+                        );
+                    }
+                }
+                if from_enum_attr.deserialize.is_some() {
+                    self.deserialize = from_enum_attr.deserialize;
+                }
+                self.strict |= from_enum_attr.strict.is_some();
             }
             Err(err) => {
                 self.errors.push(err.into());
@@ -135,47 +621,347 @@ impl<'ast> EnumParser {
         }
     }
 
-    fn parse_from_case_attrs(&self, attrs: &[Attribute]) -> SrcCasesBySrc {
-        attrs.iter().fold(HashMap::new(), |mut m, attr| {
-            if let Ok(new_attrs) = parse2::<FromCaseAttr>(attr.tokens.clone()) {
-                let new_src_cases_by_src = new_attrs.into_src_cases_by_src();
-                let known_srcs = new_src_cases_by_src
+    fn parse_from_case_attrs(&mut self, attrs: &[Attribute]) -> (SrcCasesBySrc, Exclusions) {
+        attrs
+            .iter()
+            .filter(|attr| attr.path.matches_ident("from_case"))
+            .fold(
+                (HashMap::new(), Exclusions::default()),
+                |(mut m, mut exclusions), attr| {
+                    match parse2::<FromCaseAttr>(attr.tokens.clone()) {
+                        Ok(new_attrs) => {
+                            let (mut new_src_cases_by_src, new_exclusions) =
+                                new_attrs.into_src_cases_by_src();
+                            let unknown_srcs = new_src_cases_by_src
+                                .keys()
+                                .filter_map(|src_enum| match src_enum {
+                                    SrcEnum::Single(src_enum)
+                                        if !self.src_names.contains(src_enum) =>
+                                    {
+                                        Some(src_enum.clone())
+                                    }
+                                    _ => None,
+                                })
+                                .collect::<Vec<_>>();
+                            unknown_srcs.into_iter().for_each(|src_enum| {
+                                self.errors.push(self.unknown_src_error(&src_enum));
+                                new_src_cases_by_src.remove(&SrcEnum::Single(src_enum));
+                            });
+
+                            let (known_except, unknown_except): (Vec<_>, Vec<_>) = new_exclusions
+                                .except
+                                .into_iter()
+                                .partition(|src| self.src_names.contains(src));
+                            unknown_except.iter().for_each(|src| {
+                                self.errors.push(self.unknown_src_error(src));
+                            });
+
+                            m.merge_in(new_src_cases_by_src);
+                            exclusions.skip = exclusions.skip.or(new_exclusions.skip);
+                            exclusions.except.extend(known_except);
+                        }
+                        Err(err) => {
+                            self.errors.push(err.into());
+                        }
+                    }
+
+                    (m, exclusions)
+                },
+            )
+    }
+
+    /// Reports a source enum that no from_enum lists, suggesting the closest one that does.
+    fn unknown_src_error(&self, src_enum: &Path) -> Error {
+        let name = src_enum.display_path();
+        let src_names = self
+            .src_names
+            .iter()
+            .map(DisplayPath::display_path)
+            .collect::<Vec<_>>();
+        let hint = match did_you_mean(&name, src_names.iter().map(String::as_str)) {
+            hint if hint.is_empty() => {
+                String::from("; every source enum must be listed in a #[from_enum(..)]")
+            }
+            hint => hint,
+        };
+
+        ParseError::new(
+            src_enum.span(),
+            format!("unknown source enum {}{}", name, hint),
+        )
+        .into()
+    }
+
+    /// Reports our attributes that are placed where they have no meaning, like a from_enum on a
+    /// variant, rather than silently ignoring them.
+    fn reject_misplaced_attrs(&mut self, attrs: &[Attribute], misplaced: &[&str]) {
+        attrs
+            .iter()
+            .filter_map(|attr| {
+                misplaced
                     .iter()
-                    .all(|(src_enum, _)| match src_enum {
-                        SrcEnum::All() => true,
-                        SrcEnum::Single(ref src_enum) => self.src_names.contains(src_enum),
-                    });
-                if !known_srcs {
-                    panic!("Unknown source enum");
+                    .find(|name| attr.path.matches_ident(name))
+                    .map(|name| (attr, name))
+            })
+            .for_each(|(attr, name)| {
+                let place = match *name {
+                    "from_enum" => "the enum",
+                    "from_case" => "variants",
+                    _ => "fields",
+                };
+                self.errors.push(
+                    ParseError::new(
+                        attr.path.span(),
+                        format!("#[{}] may only be used on {}", name, place),
+                    )
+                    .into(),
+                );
+            });
+    }
+
+    fn parse_from_field_attrs(&mut self, attrs: &[Attribute]) -> FieldSource {
+        attrs
+            .iter()
+            .filter(|attr| attr.path.matches_ident("from_field"))
+            .fold(FieldSource::Field(), |field_source, attr| {
+                match parse2::<FromFieldAttr>(attr.tokens.clone()) {
+                    Ok(from_field_attr) => from_field_attr.field_source,
+                    Err(err) => {
+                        self.errors.push(err.into());
+                        field_source
+                    }
                 }
+            })
+    }
 
-                m.merge_in(new_src_cases_by_src);
-            }
+    /// Ensures that the bindings of an explicit from_case pattern correspond to the fields of the
+    /// destination variant that are converted from source fields: by name for struct-like variants
+    /// and by position for tuple-like variants.
+    fn check_pattern_bindings(
+        &mut self,
+        dest: &Variant,
+        field_sources: &[FieldSource],
+        pattern: &CasePattern,
+    ) {
+        let bound_fields = dest
+            .fields
+            .iter()
+            .zip(field_sources)
+            .filter(|(_, field_source)| **field_source == FieldSource::Field())
+            .map(|(field, _)| field)
+            .collect::<Vec<_>>();
 
-            m
-        })
+        match &dest.fields {
+            Fields::Named(_) => {
+                let field_names = bound_fields
+                    .iter()
+                    .filter_map(|field| field.ident.as_ref())
+                    .collect::<HashSet<_>>();
+                let bindings = pattern.bindings.iter().collect::<HashSet<_>>();
+
+                pattern
+                    .bindings
+                    .iter()
+                    .filter(|binding| !field_names.contains(binding))
+                    .for_each(|binding| {
+                        self.errors.push(
+                            ParseError::new(
+                                binding.span(),
+                                format!("`{}` is not a field of {}", binding, dest.ident),
+                            )
+                            .into(),
+                        );
+                    });
+                field_names
+                    .iter()
+                    .filter(|field_name| !bindings.contains(*field_name))
+                    .for_each(|field_name| {
+                        self.errors.push(
+                            ParseError::new(
+                                pattern.pat.span(),
+                                format!("the pattern must bind field `{}`", field_name),
+                            )
+                            .into(),
+                        );
+                    });
+            }
+            _ => {
+                if bound_fields.len() != pattern.bindings.len() {
+                    self.errors.push(
+                        ParseError::new(
+                            pattern.pat.span(),
+                            format!(
+                                "the pattern binds {} identifier(s) but {} has {} field(s)",
+                                pattern.bindings.len(),
+                                dest.ident,
+                                bound_fields.len(),
+                            ),
+                        )
+                        .into(),
+                    );
+                }
+            }
+        }
     }
 }
 
 impl<'ast> Visit<'ast> for EnumParser {
     fn visit_attribute(&mut self, node: &'ast Attribute) {
+        self.reject_misplaced_attrs(std::slice::from_ref(node), &["from_case", "from_field"]);
         self.parse_from_enum_attr(node);
     }
 
     fn visit_variant(&mut self, node: &'ast Variant) {
         self.dest_case_order
             .insert(node.clone(), self.dest_case_order.len());
-        let mut src_cases_by_src = self.parse_from_case_attrs(&node.attrs);
-        if src_cases_by_src.is_empty() {
-            src_cases_by_src.insert(
-                SrcEnum::All(),
-                vec![SrcCase {
-                    case_name: node.ident.clone(),
-                    fallible: false,
-                }],
+        self.reject_misplaced_attrs(&node.attrs, &["from_enum", "from_field"]);
+        node.fields.iter().for_each(|field| {
+            self.reject_misplaced_attrs(&field.attrs, &["from_enum", "from_case"])
+        });
+        let (mut src_cases_by_src, exclusions) = self.parse_from_case_attrs(&node.attrs);
+        let field_sources = node
+            .fields
+            .iter()
+            .map(|field| self.parse_from_field_attrs(&field.attrs))
+            .collect::<Vec<_>>();
+        let has_wildcard = src_cases_by_src
+            .values()
+            .flatten()
+            .any(|src_case| src_case.case_name == CaseName::Wildcard() && !src_case.flatten);
+        let has_flatten = src_cases_by_src
+            .values()
+            .flatten()
+            .any(|src_case| src_case.flatten);
+        let bound_field_count = field_sources
+            .iter()
+            .filter(|field_source| **field_source == FieldSource::Field())
+            .count();
+        if has_wildcard && bound_field_count > 1 {
+            self.errors.push(
+                ParseError::new(
+                    node.ident.span(),
+                    "a wildcard from_case requires a variant with at most one field, which receives the whole source enum",
+                )
+                .into(),
+            );
+        }
+        if has_flatten && bound_field_count != 1 {
+            self.errors.push(
+                ParseError::new(
+                    node.ident.span(),
+                    "#[from_case(flatten)] requires a variant with exactly one field, which receives the nested enum",
+                )
+                .into(),
+            );
+        }
+        let has_unknown_variant = src_cases_by_src
+            .values()
+            .flatten()
+            .any(|src_case| src_case.case_name == CaseName::Wildcard());
+        if has_unknown_variant && field_sources.contains(&FieldSource::SourceVariant()) {
+            self.errors.push(
+                ParseError::new(
+                    node.ident.span(),
+                    "#[from_field(source_variant)] may not be used with a wildcard from_case",
+                )
+                .into(),
             );
         }
+        if let Some(skip) = &exclusions.skip {
+            if !src_cases_by_src.is_empty() || !exclusions.except.is_empty() {
+                self.errors.push(
+                    ParseError::new(
+                        skip.span(),
+                        "skip may not be combined with any case or except(..)",
+                    )
+                    .into(),
+                );
+            }
+        }
+        exclusions
+            .except
+            .iter()
+            .filter(|src| src_cases_by_src.contains_key(&SrcEnum::Single((*src).clone())))
+            .for_each(|src| {
+                self.errors.push(
+                    ParseError::new(
+                        src.span(),
+                        format!(
+                            "{} is excluded by except(..), so it may not also have cases for {}",
+                            src.display_path(),
+                            node.ident,
+                        ),
+                    )
+                    .into(),
+                );
+            });
+        if let Some(remote) = &self.remote {
+            let span = match (
+                &exclusions.skip,
+                exclusions.except.iter().find(|src| *src == remote),
+            ) {
+                (Some(skip), _) => Some(skip.span()),
+                (None, Some(src)) => Some(src.span()),
+                (None, None) => None,
+            };
+            if let Some(span) = span {
+                self.errors.push(
+                    ParseError::new(
+                        span,
+                        "a remote mirror variant is always converted back into the remote enum, so it may not be skipped or exclude the remote enum",
+                    )
+                    .into(),
+                );
+            }
+        }
+
+        if src_cases_by_src.is_empty() && exclusions.skip.is_none() {
+            let implicit_case = |case_name| SrcCase {
+                case_name: CaseName::Variant(case_name),
+                pattern: None,
+                guard: None,
+                flatten: false,
+                priority: None,
+                infallible: None,
+            };
+            src_cases_by_src.insert(SrcEnum::All(), vec![implicit_case(node.ident.clone())]);
+            let src_case_names = self
+                .name_rules_by_src
+                .iter()
+                .filter(|(src, _)| !exclusions.except.contains(src))
+                .map(|(src, name_rules)| (src.clone(), name_rules.src_case_name(&node.ident)))
+                .collect::<Vec<_>>();
+            src_case_names
+                .into_iter()
+                .for_each(|(src, src_case_name)| match src_case_name {
+                    Ok(src_case_name) => {
+                        src_cases_by_src
+                            .insert(SrcEnum::Single(src), vec![implicit_case(src_case_name)]);
+                    }
+                    Err(err) => self.errors.push(err.into()),
+                });
+        }
 
+        src_cases_by_src
+            .values_mut()
+            .flatten()
+            .filter_map(|src_case| src_case.pattern.as_mut())
+            .for_each(|pattern| lift_pattern(node, &field_sources, pattern));
+        src_cases_by_src
+            .values()
+            .flatten()
+            .filter_map(|src_case| src_case.pattern.as_ref())
+            .for_each(|pattern| self.check_pattern_bindings(node, &field_sources, pattern));
+
+        if exclusions.skip.is_some() {
+            self.skipped_dests.insert(node.clone());
+        }
+        if !exclusions.except.is_empty() {
+            self.excluded_srcs_by_dest
+                .insert(node.clone(), exclusions.except);
+        }
+        self.field_sources_by_dest
+            .insert(node.clone(), field_sources);
         let mut src_cases_by_src_by_dest = HashMap::new();
         src_cases_by_src_by_dest.insert(node.clone(), src_cases_by_src);
         self.src_cases_by_src_by_dest
@@ -183,10 +969,16 @@ impl<'ast> Visit<'ast> for EnumParser {
     }
 }
 
+fn case_name_string(case_name: &CaseName) -> String {
+    match case_name {
+        CaseName::Variant(case) => case.to_string(),
+        CaseName::Wildcard() => String::from("_"),
+    }
+}
+
 #[cfg(test)]
 mod enum_parser_tests {
     use super::*;
-    use quote::{format_ident, quote};
 
     #[test]
     fn parse_from_enum_single_src() -> Result<(), Error> {
@@ -197,7 +989,7 @@ mod enum_parser_tests {
                 Case2(),
             }
         };
-        let parser = EnumParser::parse(toks.into())?;
+        let parser = EnumParser::parse(toks)?;
 
         let src_names = &parser.src_names;
         let assert_has_src_name = |src: &str| {
@@ -211,7 +1003,10 @@ mod enum_parser_tests {
         };
 
         assert_has_src_name("Src1");
-        assert_eq!(parser.effect_holder_name, None);
+        assert_eq!(
+            parser.settings(&parse_quote! { Src1 }).effect_holder_name,
+            None
+        );
 
         Ok(())
     }
@@ -225,7 +1020,7 @@ mod enum_parser_tests {
                 Case2(),
             }
         };
-        let parser = EnumParser::parse(toks.into())?;
+        let parser = EnumParser::parse(toks)?;
 
         let src_names = &parser.src_names;
         let assert_has_src_name = |src: &str| {
@@ -241,7 +1036,10 @@ mod enum_parser_tests {
         assert_has_src_name("Src1");
         assert_has_src_name("Src2");
 
-        assert_eq!(parser.effect_holder_name, None);
+        assert_eq!(
+            parser.settings(&parse_quote! { Src1 }).effect_holder_name,
+            None
+        );
 
         Ok(())
     }
@@ -255,7 +1053,7 @@ mod enum_parser_tests {
                 Case2(),
             }
         };
-        let parser = EnumParser::parse(toks.into())?;
+        let parser = EnumParser::parse(toks)?;
 
         let src_names = &parser.src_names;
         let assert_has_src_name = |src: &str| {
@@ -272,7 +1070,9 @@ mod enum_parser_tests {
 
         assert_eq!(
             parser
+                .settings(&parse_quote! { Src1 })
                 .effect_holder_name
+                .clone()
                 .unwrap()
                 .get_ident()
                 .unwrap()
@@ -284,144 +1084,1166 @@ mod enum_parser_tests {
     }
 
     #[test]
-    fn parse_from_enum_srcs_no_from_enum() -> Result<(), Error> {
+    fn parse_from_enum_srcs_and_context() -> Result<(), Error> {
         let toks = quote! {
+            #[from_enum(Src1, context = MyContext)]
             enum Dest {
                 Case1(),
                 Case2(),
             }
         };
-        let res = EnumParser::parse(toks.into());
+        let parser = EnumParser::parse(toks)?;
 
-        assert!(res.is_err());
+        let context = parser
+            .settings(&parse_quote! { Src1 })
+            .context
+            .clone()
+            .unwrap();
+        assert_eq!(quote! { #context }.to_string(), String::from("MyContext"));
+        assert_eq!(
+            parser.settings(&parse_quote! { Src1 }).effect_holder_name,
+            None
+        );
 
         Ok(())
     }
 
     #[test]
-    fn parse_from_enum_srcs_bad_effect() -> Result<(), Error> {
+    fn parse_from_enum_srcs_and_trait() -> Result<(), Error> {
         let toks = quote! {
-            #[from_enum(Src1, effect_containerS = MyEffect)]
+            #[from_enum(Src1, trait = my::Convert, method = convert)]
             enum Dest {
                 Case1(),
                 Case2(),
             }
         };
-        let res = EnumParser::parse(toks.into());
+        let parser = EnumParser::parse(toks)?;
 
-        assert!(res.is_err());
+        let conversion_trait = parser
+            .settings(&parse_quote! { Src1 })
+            .conversion_trait
+            .clone()
+            .unwrap();
+        let path = &conversion_trait.path;
+        assert_eq!(
+            quote! { #path }.to_string(),
+            quote! { my::Convert }.to_string()
+        );
+        assert_eq!(conversion_trait.method.to_string(), String::from("convert"));
 
         Ok(())
     }
-}
 
-#[derive(Debug, Clone)]
-struct CaseMatch {
-    src_enum: SrcEnum,
-    src_case: SrcCase,
-}
+    #[test]
+    fn parse_from_enum_srcs_trait_and_context() -> Result<(), Error> {
+        let toks = quote! {
+            #[from_enum(Src1, context = MyContext, trait = my::Convert, method = convert)]
+            enum Dest {
+                Case1(),
+                Case2(),
+            }
+        };
+        let res = EnumParser::parse(toks);
 
-impl CaseMatch {
-    fn into_src_cases_by_src(self) -> SrcCasesBySrc {
-        let mut scbs = HashMap::new();
-        scbs.insert(self.src_enum, vec![self.src_case]);
-        scbs
-    }
-}
+        assert!(res.is_err());
 
-impl Parse for CaseMatch {
-    fn parse(input: ParseStream) -> ParseResult<Self> {
-        let parts: Vec<Path> = Punctuated::<Path, Token![=]>::parse_separated_nonempty(input)?
-            .into_iter()
-            .collect();
-        let parts: &[Path] = &parts;
-
-        match parts {
-            [src_enum, src_case] => Ok(CaseMatch {
-                src_enum: SrcEnum::Single(src_enum.clone()),
-                src_case: SrcCase {
-                    case_name: src_case.get_ident().unwrap().clone(),
-                    fallible: false,
-                },
-            }),
-            [src_case] => Ok(CaseMatch {
-                src_enum: SrcEnum::All(),
-                src_case: SrcCase {
-                    case_name: src_case.get_ident().unwrap().clone(),
-                    fallible: false,
-                },
-            }),
-            _ => Err(ParseError::new(
-                input.span(),
-                "Expected #[from_enum(SrcCase, ..)] or #[from_enum(SrcEnum = SrcCase)]",
-            )),
-        }
+        Ok(())
     }
-}
 
-#[derive(Debug, Clone)]
-struct FromCaseAttr {
-    case_matches: Vec<CaseMatch>,
-}
+    #[test]
+    fn parse_from_enum_srcs_and_fn() -> Result<(), Error> {
+        let toks = quote! {
+            #[from_enum(Src1, fn = from_src1, vis = "pub(crate)")]
+            enum Dest {
+                Case1(),
+                Case2(),
+            }
+        };
+        let parser = EnumParser::parse(toks)?;
 
-impl FromCaseAttr {
-    fn into_src_cases_by_src(self) -> SrcCasesBySrc {
-        self.case_matches
-            .into_iter()
-            .fold(HashMap::new(), |mut m, cm| {
-                m.merge_in(cm.into_src_cases_by_src());
-                m
-            })
-    }
-}
+        let conversion_fn = parser
+            .settings(&parse_quote! { Src1 })
+            .conversion_fn
+            .clone()
+            .unwrap();
+        let vis = &conversion_fn.vis;
+        assert_eq!(conversion_fn.name.to_string(), String::from("from_src1"));
+        assert_eq!(
+            quote! { #vis }.to_string(),
+            quote! { pub(crate) }.to_string()
+        );
 
-impl Parse for FromCaseAttr {
-    fn parse(input: ParseStream) -> ParseResult<Self> {
-        let content;
-        parenthesized!(content in input);
-        let case_matches = Punctuated::<CaseMatch, Token![,]>::parse_terminated(&content)?;
-        Ok(FromCaseAttr {
-            case_matches: case_matches.into_iter().collect(),
-        })
+        Ok(())
     }
-}
 
-#[derive(Debug, Clone)]
-struct FromEnumAttr {
-    sources: Vec<Path>,
-    effect: Option<Path>,
-}
+    #[test]
+    fn parse_from_case_pattern() -> Result<(), Error> {
+        let toks = quote! {
+            #[from_enum(Src1)]
+            enum Dest {
+                #[from_case(Src1 = Event(Kind::Click, pos @ Pos(_, _), ..))]
+                Click(Pos),
+                Case2(),
+            }
+        };
+        let parser = EnumParser::parse(toks)?;
 
-impl Parse for FromEnumAttr {
-    // parse a stream like (Src1, Src2, e
-    fn parse(input: ParseStream) -> ParseResult<Self> {
-        let content;
-        parenthesized!(content in input);
-        let mut sources: Vec<Path> = vec![];
-        let mut effect: Option<Path> = None;
+        let pattern = parser
+            .src_cases_by_src_by_dest
+            .values()
+            .flat_map(|src_cases_by_src| src_cases_by_src.values().flatten())
+            .find_map(|src_case| src_case.pattern.as_ref())
+            .unwrap();
+        assert_eq!(pattern.bindings, vec![format_ident!("pos")]);
 
-        loop {
-            let lhs: Path = content.parse()?;
-            if content.peek(Token![=]) {
-                content.parse::<EqToken>()?; // skip =
-                if *lhs.get_ident().unwrap() != "effect_container" {
-                    return Err(ParseError::new(
-                        lhs.span(),
-                        "from_enum only accepts source enums and effect_container = YourEffectContainerImplementingWithEffects",
-                    ));
-                }
+        Ok(())
+    }
 
+    #[test]
+    fn parse_from_field() -> Result<(), Error> {
+        let toks = quote! {
+            #[from_enum(Src1)]
+            enum Dest {
+                #[from_case(Case1, Case2)]
+                Case1(u8, #[from_field(source_variant)] &'static str),
+                Case3 {
+                    #[from_field(source_enum)]
+                    origin: &'static str,
+                },
+            }
+        };
+        let parser = EnumParser::parse(toks)?;
+
+        let mut field_sources = parser.field_sources_by_dest.values().collect::<Vec<_>>();
+        field_sources.sort_by_key(|field_sources| field_sources.len());
+        assert_eq!(
+            field_sources,
+            vec![
+                &vec![FieldSource::SourceEnum()],
+                &vec![FieldSource::Field(), FieldSource::SourceVariant()],
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_from_case_flatten() -> Result<(), Error> {
+        let toks = quote! {
+            #[from_enum(Src1, partial)]
+            enum Dest {
+                #[from_case(flatten(Case1, Case2))]
+                Nested(Inner),
+                #[from_case(flatten)]
+                Other(Other),
+            }
+        };
+        let parser = EnumParser::parse(toks)?;
+
+        assert!(parser.settings(&parse_quote! { Src1 }).partial);
+        let mut flattened = parser
+            .src_cases_by_src_by_dest
+            .values()
+            .flat_map(|src_cases_by_src| src_cases_by_src.values().flatten())
+            .filter(|src_case| src_case.flatten)
+            .map(|src_case| match &src_case.case_name {
+                CaseName::Variant(case) => case.to_string(),
+                CaseName::Wildcard() => String::from("_"),
+            })
+            .collect::<Vec<_>>();
+        flattened.sort();
+        assert_eq!(flattened, vec!["Case1", "Case2", "_"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_from_case_lift() -> Result<(), Error> {
+        let toks = quote! {
+            #[from_enum(Src1)]
+            enum Dest {
+                #[from_case(Net(NetEvent::Connect))]
+                Connect(u32, String),
+                Idle,
+            }
+        };
+        let parser = EnumParser::parse(toks)?;
+
+        let pattern = parser
+            .src_cases_by_src_by_dest
+            .values()
+            .flat_map(|src_cases_by_src| src_cases_by_src.values().flatten())
+            .find_map(|src_case| src_case.pattern.as_ref())
+            .unwrap();
+        let pat = &pattern.pat;
+        assert_eq!(
+            quote! { #pat }.to_string(),
+            quote! { Net(NetEvent::Connect(arg0, arg1)) }.to_string()
+        );
+        assert_eq!(
+            pattern.bindings,
+            vec![format_ident!("arg0"), format_ident!("arg1")]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_from_enum_rename_all() -> Result<(), Error> {
+        let toks = quote! {
+            #[from_enum(Src1)]
+            #[from_enum(Src2, rename_all = "SCREAMING_SNAKE_CASE", strip_prefix = "EventType")]
+            enum Dest {
+                HTTPClick2,
+                #[from_case(Case2)]
+                MyCase2,
+            }
+        };
+        let parser = EnumParser::parse(toks)?;
+
+        let case_names = |src: Path| {
+            parser.conversion_arms_by_src()[&src]
+                .iter()
+                .flatten()
+                .map(|cfg| match &cfg.src_case.case_name {
+                    CaseName::Variant(case) => case.to_string(),
+                    CaseName::Wildcard() => String::from("_"),
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            case_names(parse_quote! { Src1 }),
+            vec!["HTTPClick2", "Case2"]
+        );
+        assert_eq!(
+            case_names(parse_quote! { Src2 }),
+            vec!["EVENT_TYPE_HTTP_CLICK2", "Case2"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_from_case_priority() -> Result<(), Error> {
+        let toks = quote! {
+            #[from_enum(Src1)]
+            enum Dest {
+                #[from_case(Case1)]
+                First(u8),
+                #[from_case(Case1, priority = 1)]
+                Second(u8),
+                #[from_case(Case1, priority = 5)]
+                Third(u8),
+            }
+        };
+        let parser = EnumParser::parse(toks)?;
+
+        let arms = &parser.conversion_arms_by_src()[&parse_quote! { Src1 }];
+        let dests = arms[0]
+            .iter()
+            .map(|cfg| cfg.dest.ident.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(dests, vec!["Third", "Second", "First"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_from_enum_via() -> Result<(), Error> {
+        let toks = quote! {
+            #[from_enum(V2)]
+            #[from_enum(V1, via = V2)]
+            enum V3 {
+                Case1(),
+                #[from_case(Case2, V1 = Legacy)]
+                Case2(),
+            }
+        };
+        let parser = EnumParser::parse(toks)?;
+
+        let v1: Path = parse_quote! { V1 };
+        let v2: Path = parse_quote! { V2 };
+        assert_eq!(parser.via_by_src.get(&v1), Some(&v2));
+        let arms = parser.conversion_arms_by_src();
+        assert_eq!(arms[&v1].len(), 1);
+        assert_eq!(arms[&v2].len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_from_enum_via_name_rules() -> Result<(), Error> {
+        let toks = quote! {
+            #[from_enum(V1, via = V2, rename_all = "snake_case")]
+            #[from_enum(V2)]
+            enum V3 {
+                Case1(),
+            }
+        };
+        let err = EnumParser::parse(toks).unwrap_err();
+
+        assert!(err
+            .to_string()
+            .contains("via = YourIntermediate may not be combined with rename_all"));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn parse_from_enum_deserialize() -> Result<(), Error> {
+        let toks = quote! {
+            #[from_enum(V2, V1, deserialize)]
+            #[from_enum(Legacy)]
+            enum V3 {
+                Case1(),
+            }
+        };
+        let parser = EnumParser::parse(toks)?;
+
+        assert!(parser.deserialize);
+        let src_order: Vec<Path> = vec![
+            parse_quote! { V2 },
+            parse_quote! { V1 },
+            parse_quote! { Legacy },
+        ];
+        assert_eq!(parser.src_order, src_order);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_foreign_attrs() -> Result<(), Error> {
+        let toks = quote! {
+            #[from_enum(Src1)]
+            #[serde(rename_all = "snake_case")]
+            enum Dest {
+                #[serde(skip)]
+                #[strum(disabled)]
+                Case1(),
+                #[doc = "Case2"]
+                Case2(#[serde(default)] u8),
+            }
+        };
+        let parser = EnumParser::parse(toks)?;
+
+        let src1: Path = parse_quote! { Src1 };
+        let arms = parser.conversion_arms_by_src();
+        assert_eq!(arms[&src1].len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_from_case_strict() -> Result<(), Error> {
+        let toks = quote! {
+            #[from_enum(Src1)]
+            enum Dest {
+                #[from_case(Case1, = Case2)]
+                Case1(),
+                #[from_enum(Src2)]
+                Case2(#[from_case(Case2)] u8),
+            }
+        };
+        let res = EnumParser::parse(toks);
+
+        match res {
+            Err(Error::CompoundError(errors)) => assert_eq!(errors.len(), 3),
+            _ => panic!("expected 3 errors"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_from_enum_suggestion() -> Result<(), Error> {
+        let toks = quote! {
+            #[from_enum(Src1, effect_containr = MyEffect)]
+            enum Dest {
+                Case1(),
+            }
+        };
+        let err = EnumParser::parse(toks).unwrap_err();
+
+        assert!(err.to_string().contains("did you mean `effect_container`?"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_from_case_infallible_lints() -> Result<(), Error> {
+        let toks = quote! {
+            #[from_enum(Src1, strict)]
+            enum Dest {
+                #[from_case(Case1, infallible)]
+                Case1(u64),
+                #[from_case(Case1)]
+                Case2(u8),
+                #[from_case(Case3, Case3)]
+                Case3(u8),
+            }
+        };
+        let parser = EnumParser::parse(toks)?;
+
+        assert!(parser.strict);
+        let lints = parser
+            .lints()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lints,
+            vec![
+                "Case2 is unreachable for source case Case1, because Case1 is marked infallible and is tried first",
+                "source case Case3 is listed more than once for Case3",
+            ],
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_from_case_unit_many_to_one() -> Result<(), Error> {
+        let toks = quote! {
+            #[from_enum(Src1)]
+            enum Dest {
+                #[from_case(Paused, Stopped)]
+                Inactive,
+                #[from_case(Paused)]
+                Paused,
+            }
+        };
+        let parser = EnumParser::parse(toks)?;
+
+        let src1: Path = parse_quote! { Src1 };
+        let arms = parser.conversion_arms_by_src();
+        assert_eq!(arms[&src1].len(), 2);
+        let lints = parser
+            .lints()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lints,
+            vec![
+                "Paused is unreachable for source case Paused, because Inactive has no fields to try_from and is tried first",
+            ],
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_from_case_exclusions() -> Result<(), Error> {
+        let toks = quote! {
+            #[from_enum(Src1, Src2)]
+            enum Dest {
+                Case1(),
+                #[from_case(except(Src2))]
+                Case2(),
+                #[from_case(skip)]
+                Case3(),
+                #[from_case(Src1 = Case4, except(Src1))]
+                Case4(),
+            }
+        };
+        let parser = EnumParser::parse(toks);
+        assert!(parser
+            .unwrap_err()
+            .to_string()
+            .contains("Src1 is excluded by except(..), so it may not also have cases for Case4"));
+
+        let toks = quote! {
+            #[from_enum(Src1, Src2)]
+            enum Dest {
+                Case1(),
+                #[from_case(except(Src2))]
+                Case2(),
+                #[from_case(skip)]
+                Case3(),
+                #[from_case(Case4, except(Src1, Src2))]
+                Case4(),
+            }
+        };
+        let parser = EnumParser::parse(toks)?;
+
+        let arms = parser.conversion_arms_by_src();
+        assert_eq!(arms[&parse_quote! { Src1 }].len(), 2);
+        assert_eq!(arms[&parse_quote! { Src2 }].len(), 1);
+        let lints = parser
+            .lints()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(lints, vec!["no source variant converts into Case4"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_from_enum_srcs_no_from_enum() -> Result<(), Error> {
+        let toks = quote! {
+            enum Dest {
+                Case1(),
+                Case2(),
+            }
+        };
+        let res = EnumParser::parse(toks);
+
+        assert!(res.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_from_enum_srcs_bad_effect() -> Result<(), Error> {
+        let toks = quote! {
+            #[from_enum(Src1, effect_containerS = MyEffect)]
+            enum Dest {
+                Case1(),
+                Case2(),
+            }
+        };
+        let res = EnumParser::parse(toks);
+
+        assert!(res.is_err());
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CaseMatch {
+    src_enum: SrcEnum,
+    src_case: SrcCase,
+}
+
+impl CaseMatch {
+    fn into_src_cases_by_src(self) -> SrcCasesBySrc {
+        let mut scbs = HashMap::new();
+        scbs.insert(self.src_enum, vec![self.src_case]);
+        scbs
+    }
+}
+
+impl Parse for CaseMatch {
+    fn parse(input: ParseStream) -> ParseResult<Self> {
+        let parse_case_name = |input: ParseStream| -> ParseResult<Option<Path>> {
+            if input.peek(Token![_]) {
+                input.parse::<Token![_]>()?;
+                Ok(None)
+            } else {
+                input.parse().map(Some)
+            }
+        };
+        let parse_src_case = |input: ParseStream, src_case: Option<Path>| -> ParseResult<SrcCase> {
+            match src_case {
+                Some(src_case) => {
+                    // a case followed by (..) or {..} is an explicit pattern, like Event(Kind::Click, pos)
+                    let pattern = if input.peek(Paren) || input.peek(Brace) {
+                        let fields: TokenTree = input.parse()?;
+                        let pat: Pat = parse2(quote! { #src_case #fields })?;
+                        Some(CasePattern {
+                            bindings: pat.bindings(),
+                            pat,
+                        })
+                    } else {
+                        None
+                    };
+
+                    let case_name = match src_case.get_ident() {
+                        Some(case_name) => case_name.clone(),
+                        None => {
+                            return Err(ParseError::new(
+                                src_case.span(),
+                                "expected a source case; to match a variant of a nested enum, name the nested enum, like Wrapper(Nested::Case)",
+                            ));
+                        }
+                    };
+
+                    Ok(SrcCase {
+                        case_name: CaseName::Variant(case_name),
+                        pattern,
+                        guard: None,
+                        flatten: false,
+                        priority: None,
+                        infallible: None,
+                    })
+                }
+                None => Ok(SrcCase {
+                    case_name: CaseName::Wildcard(),
+                    pattern: None,
+                    guard: None,
+                    flatten: false,
+                    priority: None,
+                    infallible: None,
+                }),
+            }
+        };
+
+        let lhs = parse_case_name(input)?;
+        if !input.peek(Token![=]) {
+            return Ok(CaseMatch {
+                src_enum: SrcEnum::All(),
+                src_case: parse_src_case(input, lhs)?,
+            });
+        }
+
+        input.parse::<EqToken>()?;
+        let rhs = parse_case_name(input)?;
+        match lhs {
+            Some(src_enum) => Ok(CaseMatch {
+                src_enum: SrcEnum::Single(src_enum),
+                src_case: parse_src_case(input, rhs)?,
+            }),
+            None => Err(ParseError::new(
+                input.span(),
+                "Expected #[from_enum(SrcCase, ..)] or #[from_enum(SrcEnum = SrcCase)]",
+            )),
+        }
+    }
+}
+
+/// The source enums that a destination variant is never converted from, as configured by
+/// `#[from_case(skip)]` or `#[from_case(except(Src2))]`.
+#[derive(Debug, Clone, Default)]
+struct Exclusions {
+    skip: Option<Ident>,
+    except: Vec<Path>,
+}
+
+#[derive(Debug, Clone)]
+struct FromCaseAttr {
+    case_matches: Vec<CaseMatch>,
+    exclusions: Exclusions,
+}
+
+impl FromCaseAttr {
+    fn into_src_cases_by_src(self) -> (SrcCasesBySrc, Exclusions) {
+        let src_cases_by_src = self
+            .case_matches
+            .into_iter()
+            .fold(HashMap::new(), |mut m, cm| {
+                m.merge_in(cm.into_src_cases_by_src());
+                m
+            });
+        (src_cases_by_src, self.exclusions)
+    }
+}
+
+impl Parse for FromCaseAttr {
+    // parse a stream like (Case1, Src2 = Case2, if = rules::predicate, priority = 10) or
+    // (flatten(Case3, Case4)) or (Case5, infallible) or (skip) or (except(Src2))
+    fn parse(input: ParseStream) -> ParseResult<Self> {
+        let content;
+        parenthesized!(content in input);
+        let mut case_matches: Vec<CaseMatch> = vec![];
+        let mut guard: Option<(Token![if], Path)> = None;
+        let mut priority: Option<LitInt> = None;
+        let mut infallible: Option<Ident> = None;
+        let mut exclusions = Exclusions::default();
+
+        while !content.is_empty() {
+            if content.peek(Token![if]) {
+                let if_token = content.parse::<Token![if]>()?;
+                content.parse::<EqToken>()?; // skip =
                 let rhs: Path = content.parse()?;
-                effect.replace(rhs);
+                guard.replace((if_token, rhs));
+            } else if content.peek(Ident)
+                && content.peek2(Token![=])
+                && !content.peek3(Ident)
+                && content.fork().parse::<Ident>()? == "priority"
+            {
+                content.parse::<Ident>()?;
+                content.parse::<EqToken>()?; // skip =
+                let invalid =
+                    |span| ParseError::new(span, "priority must be a non-negative integer");
+                let rhs = content
+                    .parse::<LitInt>()
+                    .map_err(|err| invalid(err.span()))?;
+                rhs.base10_parse::<u32>().map_err(|_| invalid(rhs.span()))?;
+                priority.replace(rhs);
+            } else if content.peek(Ident)
+                && !content.peek2(Token![=])
+                && !content.peek2(Token![::])
+                && content.fork().parse::<Ident>()? == "flatten"
+            {
+                case_matches.extend(parse_flatten(&content)?);
+            } else if content.peek(Ident)
+                && !content.peek2(Token![=])
+                && !content.peek2(Token![::])
+                && !content.peek2(Paren)
+                && !content.peek2(Brace)
+                && content.fork().parse::<Ident>()? == "infallible"
+            {
+                infallible.replace(content.parse()?);
+            } else if content.peek(Ident)
+                && !content.peek2(Token![=])
+                && !content.peek2(Token![::])
+                && !content.peek2(Paren)
+                && !content.peek2(Brace)
+                && content.fork().parse::<Ident>()? == "skip"
+            {
+                exclusions.skip.replace(content.parse()?);
+            } else if content.peek(Ident)
+                && content.peek2(Paren)
+                && content.fork().parse::<Ident>()? == "except"
+            {
+                let except: Ident = content.parse()?;
+                let srcs;
+                parenthesized!(srcs in content);
+                let srcs = srcs.parse_terminated::<Path, Token![,]>(Path::parse)?;
+                if srcs.is_empty() {
+                    return Err(ParseError::new(
+                        except.span(),
+                        "except(..) must list at least one source enum",
+                    ));
+                }
+                exclusions.except.extend(srcs);
+            } else {
+                case_matches.push(content.parse()?);
+            }
+
+            if content.is_empty() {
+                break;
+            }
+            content.parse::<CommaToken>()?;
+        }
+
+        if let Some((if_token, guard)) = guard {
+            if case_matches.is_empty() {
+                return Err(ParseError::new(
+                    if_token.span,
+                    "if = your_predicate must be accompanied by at least one case",
+                ));
+            }
+            if case_matches
+                .iter()
+                .any(|case_match| case_match.src_case.flatten)
+            {
+                return Err(ParseError::new(
+                    if_token.span,
+                    "if = your_predicate may not be combined with flatten",
+                ));
+            }
+            case_matches
+                .iter_mut()
+                .for_each(|case_match| case_match.src_case.guard = Some(guard.clone()));
+        }
+
+        if let Some(priority) = priority {
+            if case_matches.is_empty() {
+                return Err(ParseError::new(
+                    priority.span(),
+                    "priority = N must be accompanied by at least one case",
+                ));
+            }
+            case_matches
+                .iter_mut()
+                .for_each(|case_match| case_match.src_case.priority = Some(priority.clone()));
+        }
+
+        if let Some(infallible) = infallible {
+            if case_matches.is_empty() {
+                return Err(ParseError::new(
+                    infallible.span(),
+                    "infallible must be accompanied by at least one case",
+                ));
+            }
+            case_matches
+                .iter_mut()
+                .for_each(|case_match| case_match.src_case.infallible = Some(infallible.clone()));
+        }
+
+        Ok(FromCaseAttr {
+            case_matches,
+            exclusions,
+        })
+    }
+}
+
+/// Expands a pattern that looks through wrapper variants into a nested enum, like
+/// `Net(NetEvent::Connect)`, into one that binds the fields of the nested variant to the fields of the
+/// destination variant, like `Net(NetEvent::Connect(arg0))`. Patterns that already bind identifiers,
+/// or destination variants without fields, are left alone.
+fn lift_pattern(dest: &Variant, field_sources: &[FieldSource], pattern: &mut CasePattern) {
+    fn leaf_path(pat: &mut Pat) -> Option<&mut Pat> {
+        match pat {
+            Pat::TupleStruct(tuple_struct) if tuple_struct.pat.elems.len() == 1 => {
+                let inner = tuple_struct.pat.elems.first_mut().unwrap();
+                match inner {
+                    Pat::Path(_) => Some(inner),
+                    _ => leaf_path(inner),
+                }
+            }
+            _ => None,
+        }
+    }
+
+    let bindings = dest
+        .fields
+        .iter()
+        .enumerate()
+        .zip(field_sources)
+        .filter(|(_, field_source)| **field_source == FieldSource::Field())
+        .map(|((i, field), _)| {
+            field
+                .ident
+                .clone()
+                .unwrap_or_else(|| format_ident!("arg{}", i))
+        })
+        .collect::<Vec<_>>();
+    if bindings.is_empty() || !pattern.bindings.is_empty() {
+        return;
+    }
+
+    if let Some(leaf) = leaf_path(&mut pattern.pat) {
+        let fields = match &dest.fields {
+            Fields::Named(_) => quote! { { #(#bindings),* } },
+            _ => quote! { (#(#bindings),*) },
+        };
+        if let Ok(lifted) = parse2::<Pat>(quote! { #leaf #fields }) {
+            *leaf = lifted;
+            pattern.bindings = bindings;
+        }
+    }
+}
+
+// parse a stream like flatten or flatten(Case1, Case2)
+fn parse_flatten(input: ParseStream) -> ParseResult<Vec<CaseMatch>> {
+    let flatten: Ident = input.parse()?;
+    let flatten_case = |case_name| CaseMatch {
+        src_enum: SrcEnum::All(),
+        src_case: SrcCase {
+            case_name,
+            pattern: None,
+            guard: None,
+            flatten: true,
+            priority: None,
+            infallible: None,
+        },
+    };
+
+    if !input.peek(Paren) {
+        return Ok(vec![flatten_case(CaseName::Wildcard())]);
+    }
+
+    let content;
+    parenthesized!(content in input);
+    let cases = content.parse_terminated::<Ident, Token![,]>(Ident::parse)?;
+    if cases.is_empty() {
+        return Err(ParseError::new(
+            flatten.span(),
+            "flatten(..) must list at least one case",
+        ));
+    }
+
+    Ok(cases
+        .into_iter()
+        .map(|case| flatten_case(CaseName::Variant(case)))
+        .collect())
+}
+
+#[derive(Debug, Clone)]
+struct FromFieldAttr {
+    field_source: FieldSource,
+}
+
+impl Parse for FromFieldAttr {
+    // parse a stream like (source_variant)
+    fn parse(input: ParseStream) -> ParseResult<Self> {
+        let content;
+        parenthesized!(content in input);
+        let kind: Ident = content.parse()?;
+        let field_source = if kind == "source_variant" {
+            FieldSource::SourceVariant()
+        } else if kind == "source_enum" {
+            FieldSource::SourceEnum()
+        } else {
+            return Err(ParseError::new(
+                kind.span(),
+                format!(
+                    "from_field only accepts source_variant or source_enum{}",
+                    did_you_mean(&kind.to_string(), ["source_variant", "source_enum"]),
+                ),
+            ));
+        };
+
+        if !content.is_empty() {
+            return Err(content.error("from_field accepts a single option"));
+        }
+
+        Ok(FromFieldAttr { field_source })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FromEnumAttr {
+    sources: Vec<Path>,
+    settings: ConversionSettings,
+    remote: Option<Path>,
+    name_rules: NameRules,
+    via: Option<Path>,
+    migration: Option<Ident>,
+    deserialize: Option<Ident>,
+    strict: Option<Ident>,
+}
+
+const FROM_ENUM_FLAGS: &[&str] = &["partial", "migration", "deserialize", "strict"];
+
+const FROM_ENUM_OPTIONS: &[&str] = &[
+    "effect_container",
+    "context",
+    "trait",
+    "method",
+    "fn",
+    "vis",
+    "remote",
+    "rename_all",
+    "strip_prefix",
+    "strip_suffix",
+    "via",
+];
+
+impl Parse for FromEnumAttr {
+    // parse a stream like (Src1, Src2, effect_container = E, trait = my::Convert, method = convert)
+    // or (Src1, fn = from_src1, vis = "pub(crate)") or (remote = "other_crate::Src") or (Src1, partial)
+    // or (Src1, rename_all = "SCREAMING_SNAKE_CASE", strip_prefix = "EventType") or (V1, via = V2)
+    // or (EventV1, migration) or (V1, V2, deserialize) or (Src1, strict)
+    fn parse(input: ParseStream) -> ParseResult<Self> {
+        let content;
+        parenthesized!(content in input);
+        let mut sources: Vec<Path> = vec![];
+        let mut effect: Option<Path> = None;
+        let mut context: Option<Type> = None;
+        let mut trait_path: Option<Path> = None;
+        let mut method: Option<Ident> = None;
+        let mut fn_name: Option<Ident> = None;
+        let mut vis: Option<(LitStr, Visibility)> = None;
+        let mut remote: Option<Path> = None;
+        let mut partial: Option<Ident> = None;
+        let mut name_rules = NameRules::default();
+        let mut via: Option<Path> = None;
+        let mut migration: Option<Ident> = None;
+        let mut deserialize: Option<Ident> = None;
+        let mut strict: Option<Ident> = None;
+
+        loop {
+            if content.peek(Ident::peek_any) && content.peek2(Token![=]) {
+                let key = content.call(Ident::parse_any)?;
+                content.parse::<EqToken>()?; // skip =
+                if key == "effect_container" {
+                    let rhs: Path = content.parse()?;
+                    effect.replace(rhs);
+                } else if key == "context" {
+                    let rhs: Type = content.parse()?;
+                    context.replace(rhs);
+                } else if key == "trait" {
+                    let rhs: Path = content.parse()?;
+                    trait_path.replace(rhs);
+                } else if key == "method" {
+                    let rhs: Ident = content.parse()?;
+                    method.replace(rhs);
+                } else if key == "fn" {
+                    let rhs: Ident = content.parse()?;
+                    fn_name.replace(rhs);
+                } else if key == "vis" {
+                    let rhs: LitStr = content.parse()?;
+                    let parsed: Visibility = rhs.parse()?;
+                    vis.replace((rhs, parsed));
+                } else if key == "remote" {
+                    let rhs: LitStr = content.parse()?;
+                    remote.replace(rhs.parse()?);
+                } else if key == "rename_all" {
+                    let rhs: LitStr = content.parse()?;
+                    name_rules.rename_all.replace(RenameRule::parse(&rhs)?);
+                } else if key == "strip_prefix" {
+                    let rhs: LitStr = content.parse()?;
+                    name_rules.strip_prefix.replace(parse_affix(&rhs)?);
+                } else if key == "strip_suffix" {
+                    let rhs: LitStr = content.parse()?;
+                    name_rules.strip_suffix.replace(parse_affix(&rhs)?);
+                } else if key == "via" {
+                    let rhs: Path = content.parse()?;
+                    via.replace(rhs);
+                } else {
+                    return Err(ParseError::new(
+                        key.span(),
+                        format!(
+                            "from_enum only accepts source enums, partial, migration, deserialize, strict and the options effect_container, context, trait, method, fn, vis, remote, rename_all, strip_prefix, strip_suffix and via{}",
+                            did_you_mean(&key.to_string(), FROM_ENUM_OPTIONS.iter().copied()),
+                        ),
+                    ));
+                }
             } else {
-                sources.push(lhs);
+                let source: Path = content.parse()?;
+                match source.get_ident() {
+                    Some(ident) if ident == "partial" => {
+                        partial.replace(ident.clone());
+                    }
+                    Some(ident) if ident == "migration" => {
+                        migration.replace(ident.clone());
+                    }
+                    Some(ident) if ident == "deserialize" => {
+                        deserialize.replace(ident.clone());
+                    }
+                    Some(ident) if ident == "strict" => {
+                        strict.replace(ident.clone());
+                    }
+                    _ => {
+                        // a lowercase name is far more likely a misspelled flag than a source enum
+                        let flag_hint = source
+                            .get_ident()
+                            .map(Ident::to_string)
+                            .filter(|name| name.starts_with(char::is_lowercase))
+                            .map(|name| did_you_mean(&name, FROM_ENUM_FLAGS.iter().copied()))
+                            .unwrap_or_default();
+                        if !flag_hint.is_empty() {
+                            return Err(ParseError::new(
+                                source.span(),
+                                format!(
+                                    "from_enum only accepts source enums, partial, migration, deserialize, strict and the options effect_container, context, trait, method, fn, vis, remote, rename_all, strip_prefix, strip_suffix and via{}",
+                                    flag_hint,
+                                ),
+                            ));
+                        }
+                        sources.push(source);
+                    }
+                }
             }
 
             if content.peek(Token![,]) {
                 content.parse::<CommaToken>()?;
             } else {
-                return Ok(FromEnumAttr { sources, effect });
+                break;
+            }
+        }
+
+        let conversion_trait = match (trait_path, method) {
+            (Some(path), Some(method)) => Some(ConversionTrait { path, method }),
+            (None, None) => None,
+            (Some(path), None) => {
+                return Err(ParseError::new(
+                    path.span(),
+                    "trait = YourTrait must be accompanied by method = your_method",
+                ));
+            }
+            (None, Some(method)) => {
+                return Err(ParseError::new(
+                    method.span(),
+                    "method = your_method must be accompanied by trait = YourTrait",
+                ));
+            }
+        };
+
+        if let (Some(_), Some(conversion_trait)) = (&context, &conversion_trait) {
+            return Err(ParseError::new(
+                conversion_trait.path.span(),
+                "context may not be combined with trait",
+            ));
+        }
+
+        let conversion_fn = match (fn_name, vis) {
+            (Some(name), vis) => Some(ConversionFn {
+                name,
+                vis: vis
+                    .map(|(_, vis)| vis)
+                    .unwrap_or_else(|| parse_quote! { pub }),
+            }),
+            (None, None) => None,
+            (None, Some((lit, _))) => {
+                return Err(ParseError::new(
+                    lit.span(),
+                    "vis = \"..\" must be accompanied by fn = your_fn",
+                ));
+            }
+        };
+
+        if let Some(partial) = &partial {
+            if context.is_some() || conversion_trait.is_some() || conversion_fn.is_some() {
+                return Err(ParseError::new(
+                    partial.span(),
+                    "partial may not be combined with context, trait or fn",
+                ));
+            }
+        }
+
+        if let Some(migration) = &migration {
+            if sources.len() > 1 || remote.is_some() {
+                return Err(ParseError::new(
+                    migration.span(),
+                    "migration accepts at most one source enum, the previous version",
+                ));
+            }
+            if context.is_some()
+                || conversion_trait.is_some()
+                || conversion_fn.is_some()
+                || partial.is_some()
+            {
+                return Err(ParseError::new(
+                    migration.span(),
+                    "migration may not be combined with context, trait, fn or partial",
+                ));
+            }
+        }
+
+        if let Some(deserialize) = &deserialize {
+            if !cfg!(feature = "serde") {
+                return Err(ParseError::new(
+                    deserialize.span(),
+                    "deserialize requires the serde feature of enum_to_enum",
+                ));
             }
+            if effect.is_some()
+                || context.is_some()
+                || conversion_trait.is_some()
+                || conversion_fn.is_some()
+                || partial.is_some()
+            {
+                return Err(ParseError::new(
+                    deserialize.span(),
+                    "deserialize may not be combined with effect_container, context, trait, fn or partial",
+                ));
+            }
+        }
+
+        if let Some(conversion_fn) = &conversion_fn {
+            if sources.len() + remote.iter().count() != 1 {
+                return Err(ParseError::new(
+                    conversion_fn.name.span(),
+                    "fn = your_fn requires exactly one source enum",
+                ));
+            }
+        }
+
+        if let (Some(via), true) = (&via, sources.is_empty()) {
+            return Err(ParseError::new(
+                via.span(),
+                "via = YourIntermediate must be accompanied by at least one source enum",
+            ));
+        }
+        if let (Some(via), false) = (&via, name_rules.is_empty()) {
+            return Err(ParseError::new(
+                via.span(),
+                "via = YourIntermediate may not be combined with rename_all, strip_prefix or strip_suffix, since unannotated variants are converted through the intermediate enum",
+            ));
         }
+
+        Ok(FromEnumAttr {
+            sources,
+            settings: ConversionSettings {
+                effect_holder_name: effect,
+                context,
+                conversion_trait,
+                conversion_fn,
+                partial: partial.is_some(),
+            },
+            remote,
+            name_rules,
+            via,
+            migration,
+            deserialize,
+            strict,
+        })
     }
 }
@@ -0,0 +1,169 @@
+use crate::suggest::did_you_mean;
+use syn::{
+    parse::{Error as ParseError, Result as ParseResult},
+    Ident, LitStr,
+};
+
+/// A case convention for source variant names, as in `rename_all = "SCREAMING_SNAKE_CASE"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenameRule {
+    Lower(),
+    Upper(),
+    Pascal(),
+    Camel(),
+    Snake(),
+    ScreamingSnake(),
+}
+
+impl RenameRule {
+    pub fn parse(lit: &LitStr) -> ParseResult<RenameRule> {
+        match lit.value().as_str() {
+            "lowercase" => Ok(RenameRule::Lower()),
+            "UPPERCASE" => Ok(RenameRule::Upper()),
+            "PascalCase" => Ok(RenameRule::Pascal()),
+            "camelCase" => Ok(RenameRule::Camel()),
+            "snake_case" => Ok(RenameRule::Snake()),
+            "SCREAMING_SNAKE_CASE" => Ok(RenameRule::ScreamingSnake()),
+            value => Err(ParseError::new(
+                lit.span(),
+                format!(
+                    "rename_all must be one of \"lowercase\", \"UPPERCASE\", \"PascalCase\", \"camelCase\", \"snake_case\" or \"SCREAMING_SNAKE_CASE\"{}",
+                    did_you_mean(
+                        value,
+                        ["lowercase", "UPPERCASE", "PascalCase", "camelCase", "snake_case", "SCREAMING_SNAKE_CASE"],
+                    ),
+                ),
+            )),
+        }
+    }
+
+    fn apply(&self, name: &str) -> String {
+        let words = words(name);
+        match self {
+            RenameRule::Lower() => words.concat().to_lowercase(),
+            RenameRule::Upper() => words.concat().to_uppercase(),
+            RenameRule::Pascal() => words.iter().map(|word| capitalize(word)).collect(),
+            RenameRule::Camel() => words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| {
+                    if i == 0 {
+                        word.to_lowercase()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect(),
+            RenameRule::Snake() => words.join("_").to_lowercase(),
+            RenameRule::ScreamingSnake() => words.join("_").to_uppercase(),
+        }
+    }
+}
+
+/// Rules to compute the name of the source variant that an unannotated destination variant is
+/// converted from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NameRules {
+    pub rename_all: Option<RenameRule>,
+    pub strip_prefix: Option<String>,
+    pub strip_suffix: Option<String>,
+}
+
+impl NameRules {
+    pub fn is_empty(&self) -> bool {
+        *self == NameRules::default()
+    }
+
+    /// Returns the source variant name for the destination variant `dest`: the destination name
+    /// with the stripped prefix and suffix restored, in the case convention of the source enum.
+    pub fn src_case_name(&self, dest: &Ident) -> ParseResult<Ident> {
+        let name = format!(
+            "{}{}{}",
+            self.strip_prefix.as_deref().unwrap_or_default(),
+            dest,
+            self.strip_suffix.as_deref().unwrap_or_default(),
+        );
+        let name = match &self.rename_all {
+            Some(rename_all) => rename_all.apply(&name),
+            None => name,
+        };
+
+        // the case convention may still turn the name into a keyword, like `type`
+        let mut ident = syn::parse_str::<Ident>(&name).map_err(|_| {
+            ParseError::new(
+                dest.span(),
+                format!(
+                    "the name rules turn {} into `{}`, which is not a valid variant name",
+                    dest, name,
+                ),
+            )
+        })?;
+        ident.set_span(dest.span());
+
+        Ok(ident)
+    }
+}
+
+/// Parses a `strip_prefix` or `strip_suffix`, which must consist of identifier characters so that
+/// restoring it yields a valid variant name.
+pub fn parse_affix(lit: &LitStr) -> ParseResult<String> {
+    let value = lit.value();
+    if !value.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return Err(ParseError::new(
+            lit.span(),
+            format!(
+                "{:?} may only contain letters, digits and underscores, since it is part of a variant name",
+                value,
+            ),
+        ));
+    }
+
+    Ok(value)
+}
+
+/// Splits a name, like `HTTPStatus_code2`, into its words, like `HTTP`, `Status` and `code2`.
+fn words(name: &str) -> Vec<String> {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut words = vec![];
+    let mut word = String::new();
+
+    for (i, c) in chars.iter().enumerate() {
+        if *c == '_' {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+
+        let prev = if i > 0 { chars.get(i - 1) } else { None };
+        let next = chars.get(i + 1);
+        let starts_word = c.is_uppercase()
+            && match prev {
+                Some(prev) if prev.is_lowercase() || prev.is_numeric() => true,
+                Some(prev) if prev.is_uppercase() => {
+                    matches!(next, Some(next) if next.is_lowercase())
+                }
+                _ => false,
+            };
+        if starts_word && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+        word.push(*c);
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,32 @@
+/// Returns a hint naming the candidate closest to `name`, like "; did you mean `effect_container`?",
+/// or an empty string if no candidate is close enough to be a likely typo.
+pub fn did_you_mean<'a, I: IntoIterator<Item = &'a str>>(name: &str, candidates: I) -> String {
+    let max_distance = std::cmp::max(1, name.chars().count() / 3);
+
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min()
+        .map(|(_, candidate)| format!("; did you mean `{}`?", candidate))
+        .unwrap_or_default()
+}
+
+/// The Levenshtein distance between `a` and `b`: the number of single-character insertions,
+/// deletions and substitutions needed to turn one into the other.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut distances = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut prev_diagonal = distances[0];
+        distances[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = prev_diagonal + usize::from(a_char != *b_char);
+            prev_diagonal = distances[j + 1];
+            distances[j + 1] = substitution.min(distances[j] + 1).min(distances[j + 1] + 1);
+        }
+    }
+
+    distances[b.len()]
+}
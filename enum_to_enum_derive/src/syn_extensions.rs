@@ -1,4 +1,7 @@
-use syn::Path;
+use syn::{
+    visit::{visit_pat_ident, Visit},
+    Ident, Pat, PatIdent, Path, PathArguments,
+};
 
 pub trait MatchesIdent {
     fn matches_ident(&self, ident: &str) -> bool;
@@ -9,3 +12,66 @@ impl MatchesIdent for Path {
         self.get_ident().map(|id| *id == target_id).unwrap_or(false)
     }
 }
+
+pub trait ToExprPath {
+    fn to_expr_path(&self) -> Self;
+}
+
+impl ToExprPath for Path {
+    /// Converts a type path, like `Option<u8>`, into a path usable in expressions and patterns, like
+    /// `Option::<u8>`.
+    fn to_expr_path(&self) -> Path {
+        let mut path = self.clone();
+        path.segments.iter_mut().for_each(|segment| {
+            if let PathArguments::AngleBracketed(args) = &mut segment.arguments {
+                args.colon2_token.get_or_insert_with(Default::default);
+            }
+        });
+        path
+    }
+}
+
+pub trait DisplayPath {
+    fn display_path(&self) -> String;
+}
+
+impl DisplayPath for Path {
+    /// Renders the path without generic arguments, like `other_crate::Src`, for use in messages.
+    fn display_path(&self) -> String {
+        self.segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect::<Vec<_>>()
+            .join("::")
+    }
+}
+
+pub trait Bindings {
+    fn bindings(&self) -> Vec<Ident>;
+}
+
+impl Bindings for Pat {
+    /// Returns the identifiers bound by this pattern, in the order in which they appear.
+    /// A bare uppercase identifier, like `None` or `MAX`, names a unit variant or a constant rather
+    /// than binding a value, unless it is marked with `ref` or `mut` or has an `@` subpattern.
+    fn bindings(&self) -> Vec<Ident> {
+        struct BindingVisitor(Vec<Ident>);
+
+        impl<'ast> Visit<'ast> for BindingVisitor {
+            fn visit_pat_ident(&mut self, node: &'ast PatIdent) {
+                let is_path = node.by_ref.is_none()
+                    && node.mutability.is_none()
+                    && node.subpat.is_none()
+                    && node.ident.to_string().starts_with(char::is_uppercase);
+                if !is_path {
+                    self.0.push(node.ident.clone());
+                }
+                visit_pat_ident(self, node);
+            }
+        }
+
+        let mut visitor = BindingVisitor(vec![]);
+        visitor.visit_pat(self);
+        visitor.0
+    }
+}
@@ -0,0 +1,59 @@
+use enum_to_enum::FromEnum;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Src {
+    Running(u8),
+    Traced(u8),
+    Level(u16),
+    Moved {
+        x: u8,
+        #[cfg(any())]
+        y: u8,
+    },
+    Stopped,
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(Src)]
+enum Dest {
+    #[cfg(all())]
+    Running(u8),
+    #[cfg(any())]
+    Traced(u8),
+    #[cfg(any())]
+    #[from_case(Level, priority = 1)]
+    Level(u8),
+    #[from_case(Level)]
+    Overflow,
+    Moved {
+        x: u8,
+        #[cfg(any())]
+        y: u8,
+    },
+    #[from_case(_)]
+    Other,
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(Src)]
+enum Fine {
+    #[cfg(any())]
+    #[from_case(Running, Traced)]
+    Active(u16),
+    #[cfg(any())]
+    #[from_case(Running, Traced)]
+    Busy,
+    #[from_case(_)]
+    Other,
+}
+
+fn main() {
+    assert_eq!(Dest::from(Src::Running(3)), Dest::Running(3));
+    assert_eq!(Dest::from(Src::Traced(3)), Dest::Other);
+    assert_eq!(Dest::from(Src::Level(7)), Dest::Overflow);
+    assert_eq!(Dest::from(Src::Moved { x: 1 }), Dest::Moved { x: 1 });
+    assert_eq!(Dest::from(Src::Stopped), Dest::Other);
+
+    assert_eq!(Fine::from(Src::Running(3)), Fine::Other);
+    assert_eq!(Fine::from(Src::Level(3)), Fine::Other);
+}
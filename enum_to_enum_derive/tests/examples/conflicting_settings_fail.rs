@@ -0,0 +1,14 @@
+use enum_to_enum::FromEnum;
+
+enum Src {
+    Case1(u8),
+}
+
+#[derive(FromEnum)]
+#[from_enum(Src)]
+#[from_enum(Src, partial)]
+enum Dest {
+    Case1(u8),
+}
+
+fn main() {}
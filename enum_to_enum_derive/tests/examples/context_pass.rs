@@ -0,0 +1,164 @@
+use enum_to_enum::{FromEnum, FromEnumWith, TryFromWith, WithEffects};
+
+#[derive(Debug, Default)]
+struct Allocator {
+    next_id: u32,
+}
+
+impl Allocator {
+    fn allocate(&mut self) -> u32 {
+        self.next_id += 1;
+        self.next_id
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Name(String);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Id(u32, String);
+
+impl enum_to_enum::FromWith<Name, Allocator> for Id {
+    fn from_with(name: Name, ctx: &mut Allocator) -> Id {
+        Id(ctx.allocate(), name.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Src {
+    Case1(Name),
+    Case2 { a: Name, b: u8 },
+    Case3(),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(Src, context = Allocator)]
+enum SimpleDest {
+    Case1(Id),
+    Case2 { a: Id, b: u8 },
+    #[from_case(Case3)]
+    MyCase3(),
+}
+
+enum Outer {
+    Wrapped(Src),
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(Outer, context = Allocator)]
+enum NestedDest {
+    Wrapped(SimpleDest),
+}
+
+enum FallibleSrc {
+    C1(u16),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Small(u32, u16);
+
+impl TryFromWith<u16, Allocator> for Small {
+    type Error = ();
+
+    fn try_from_with(u: u16, ctx: &mut Allocator) -> Result<Small, ()> {
+        if u < 100 {
+            Ok(Small(ctx.allocate(), u))
+        } else {
+            Err(())
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(FallibleSrc, context = Allocator)]
+enum FallibleDest {
+    #[from_case(C1)]
+    Small(Small),
+    #[from_case(C1)]
+    Big(u16),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum MyEffect {
+    Allocated(u32),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct EffectHolder<Value> {
+    value: Value,
+    effects: Vec<MyEffect>,
+}
+
+impl<Value> WithEffects for EffectHolder<Value> {
+    type Value = Value;
+    type Effect = MyEffect;
+
+    fn new(value: Self::Value, effects: Vec<Self::Effect>) -> Self {
+        Self { value, effects }
+    }
+
+    fn into_value_and_effects(self) -> (Self::Value, Box<dyn Iterator<Item = Self::Effect>>) {
+        (self.value, Box::new(self.effects.into_iter()))
+    }
+}
+
+impl enum_to_enum::FromWith<Name, Allocator> for EffectHolder<Id> {
+    fn from_with(name: Name, ctx: &mut Allocator) -> EffectHolder<Id> {
+        let id = ctx.allocate();
+        EffectHolder {
+            value: Id(id, name.0),
+            effects: vec![MyEffect::Allocated(id)],
+        }
+    }
+}
+
+impl From<u8> for EffectHolder<u8> {
+    fn from(u: u8) -> EffectHolder<u8> {
+        EffectHolder {
+            value: u,
+            effects: vec![],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(Src, effect_container = EffectHolder, context = Allocator)]
+enum EffectDest {
+    Case1(Id),
+    Case2 { a: Id, b: u8 },
+    #[from_case(Case3)]
+    MyCase3(),
+}
+
+fn main() {
+    let mut ctx = Allocator::default();
+
+    assert_eq!(
+        SimpleDest::from_with(Src::Case1(Name("a".to_string())), &mut ctx),
+        SimpleDest::Case1(Id(1, "a".to_string())),
+    );
+    assert_eq!(
+        SimpleDest::from_with(Src::Case2 { a: Name("b".to_string()), b: 3 }, &mut ctx),
+        SimpleDest::Case2 { a: Id(2, "b".to_string()), b: 3 },
+    );
+    assert_eq!(SimpleDest::from_with(Src::Case3(), &mut ctx), SimpleDest::MyCase3());
+    assert_eq!(
+        NestedDest::from_with(Outer::Wrapped(Src::Case1(Name("c".to_string()))), &mut ctx),
+        NestedDest::Wrapped(SimpleDest::Case1(Id(3, "c".to_string()))),
+    );
+    assert_eq!(
+        FallibleDest::from_with(FallibleSrc::C1(10), &mut ctx),
+        FallibleDest::Small(Small(4, 10)),
+    );
+    assert_eq!(
+        FallibleDest::from_with(FallibleSrc::C1(1000), &mut ctx),
+        FallibleDest::Big(1000),
+    );
+    assert_eq!(
+        EffectHolder::<EffectDest>::from_with(Src::Case1(Name("d".to_string())), &mut ctx),
+        EffectHolder {
+            value: EffectDest::Case1(Id(5, "d".to_string())),
+            effects: vec![MyEffect::Allocated(5)],
+        },
+    );
+}
@@ -0,0 +1,27 @@
+use enum_to_enum::FromEnum;
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+enum Src1 {
+    Case1,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+enum Src2 {
+    Case1,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(Src1)]
+#[from_enum(Src2, strict)]
+enum Strict {
+    Case1,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(Src1)]
+#[from_enum(Src2, deserialize)]
+enum Deserialized {
+    Case1,
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+use enum_to_enum::FromEnum;
+
+enum Src {
+    Case1(u8),
+    Case2(u8),
+}
+
+#[derive(FromEnum)]
+#[from_enum(Src, partial)]
+enum Inner {
+    Case1(u8),
+}
+
+#[derive(FromEnum)]
+#[from_enum(Src)]
+enum Dest {
+    #[from_case(flatten(Case1))]
+    Nested(Inner, u8),
+    Case2(u8),
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+use enum_to_enum::FromEnum;
+
+enum Src {
+    Connect(u32),
+    Disconnect(u32),
+}
+
+#[derive(FromEnum)]
+#[from_enum(Src, partial)]
+enum NetEvent {
+    Connect(u32),
+}
+
+#[derive(FromEnum)]
+#[from_enum(Src, partial)]
+enum Dest {
+    #[from_case(flatten(Connect))]
+    Net(NetEvent),
+    #[from_case(Connect, Disconnect)]
+    Other(u32),
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+use enum_to_enum::FromEnum;
+
+enum Src {
+    Case1(u8),
+    Case2(u8),
+}
+
+#[derive(FromEnum)]
+#[from_enum(Src, partial)]
+enum Inner {
+    Case1(u8),
+}
+
+#[derive(FromEnum)]
+#[from_enum(Src)]
+enum Dest {
+    #[from_case(flatten)]
+    Nested(Inner),
+}
+
+#[derive(FromEnum)]
+#[from_enum(Src)]
+enum Listed {
+    #[from_case(flatten(Case1, Case2))]
+    Nested(Inner),
+}
+
+fn main() {}
@@ -0,0 +1,86 @@
+use enum_to_enum::FromEnum;
+use std::convert::TryFrom;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Event {
+    Connect(u32),
+    Disconnect(u32),
+    Read { bytes: u64 },
+    Write { bytes: u64 },
+    Tick,
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(Event, partial)]
+enum NetEvent {
+    Connect(u32),
+    Disconnect(u32),
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(Event, partial)]
+enum DiskEvent {
+    Read { bytes: u64 },
+    Write { bytes: u64 },
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(Event, partial)]
+enum Dest {
+    #[from_case(flatten(Connect, Disconnect))]
+    Net(NetEvent),
+    #[from_case(flatten(Read, Write))]
+    Disk { event: DiskEvent },
+    Tick,
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(Event, partial)]
+enum IoDest {
+    #[from_case(flatten)]
+    Net(NetEvent),
+    #[from_case(flatten)]
+    Disk(DiskEvent),
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(Event, partial)]
+enum OnlyConnect {
+    Connect(u32),
+}
+
+// the nested enum does not accept every listed variant, which is handed back instead
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(Event, partial)]
+enum Lossy {
+    #[from_case(flatten(Connect, Disconnect))]
+    Net(OnlyConnect),
+}
+
+fn main() {
+    assert_eq!(
+        NetEvent::try_from(Event::Connect(1)),
+        Ok(NetEvent::Connect(1)),
+    );
+    assert_eq!(NetEvent::try_from(Event::Tick), Err(Event::Tick));
+    assert_eq!(
+        Dest::try_from(Event::Disconnect(2)),
+        Ok(Dest::Net(NetEvent::Disconnect(2))),
+    );
+    assert_eq!(
+        Dest::try_from(Event::Write { bytes: 3 }),
+        Ok(Dest::Disk { event: DiskEvent::Write { bytes: 3 } }),
+    );
+    assert_eq!(Dest::try_from(Event::Tick), Ok(Dest::Tick));
+    assert_eq!(Lossy::try_from(Event::Connect(6)), Ok(Lossy::Net(OnlyConnect::Connect(6))));
+    assert_eq!(Lossy::try_from(Event::Disconnect(7)), Err(Event::Disconnect(7)));
+    assert_eq!(
+        IoDest::try_from(Event::Connect(4)),
+        Ok(IoDest::Net(NetEvent::Connect(4))),
+    );
+    assert_eq!(
+        IoDest::try_from(Event::Read { bytes: 5 }),
+        Ok(IoDest::Disk(DiskEvent::Read { bytes: 5 })),
+    );
+    assert_eq!(IoDest::try_from(Event::Tick), Err(Event::Tick));
+}
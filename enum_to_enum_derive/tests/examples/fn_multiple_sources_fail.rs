@@ -0,0 +1,17 @@
+use enum_to_enum::FromEnum;
+
+enum Src1 {
+    Case1(u8),
+}
+
+enum Src2 {
+    Case1(u8),
+}
+
+#[derive(FromEnum)]
+#[from_enum(Src1, Src2, fn = from_either)]
+enum Dest {
+    Case1(u8),
+}
+
+fn main() {}
@@ -0,0 +1,64 @@
+use enum_to_enum::FromEnum;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Src {
+    Case1(String),
+    Case2(),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(Src, fn = from_legacy)]
+enum PublicDest {
+    Case1(String),
+    #[from_case(Case2)]
+    MyCase2(),
+}
+
+mod inner {
+    use enum_to_enum::FromEnum;
+
+    #[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+    #[from_enum(super::Src, fn = from_src, vis = "pub(crate)")]
+    pub enum CrateDest {
+        Case1(String),
+        Case2(),
+    }
+}
+
+#[derive(Default)]
+struct Counter(usize);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Counted(usize, String);
+
+impl enum_to_enum::FromWith<String, Counter> for Counted {
+    fn from_with(s: String, counter: &mut Counter) -> Counted {
+        counter.0 += 1;
+        Counted(counter.0, s)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(Src, fn = from_counted, context = Counter)]
+enum ContextDest {
+    Case1(Counted),
+    Case2(),
+}
+
+fn main() {
+    assert_eq!(
+        PublicDest::from_legacy(Src::Case1("hi".to_string())),
+        PublicDest::Case1("hi".to_string()),
+    );
+    assert_eq!(PublicDest::from_legacy(Src::Case2()), PublicDest::MyCase2());
+    assert_eq!(
+        inner::CrateDest::from_src(Src::Case2()),
+        inner::CrateDest::Case2(),
+    );
+
+    let mut counter = Counter::default();
+    assert_eq!(
+        ContextDest::from_counted(Src::Case1("a".to_string()), &mut counter),
+        ContextDest::Case1(Counted(1, "a".to_string())),
+    );
+}
@@ -0,0 +1,16 @@
+use enum_to_enum::FromEnum;
+
+enum Src {
+    Case1(u8),
+    Case2(u8),
+}
+
+#[derive(FromEnum)]
+#[from_enum(Src)]
+enum Dest {
+    Case1(u8, #[from_field(source_case)] &'static str),
+    #[from_case(_)]
+    Other(#[from_field(source_variant)] &'static str),
+}
+
+fn main() {}
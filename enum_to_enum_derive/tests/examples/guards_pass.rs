@@ -0,0 +1,78 @@
+use enum_to_enum::FromEnum;
+
+mod rules {
+    pub fn is_large(amount: &u64, _to: &String) -> bool {
+        *amount >= 10_000
+    }
+
+    pub fn is_huge(amount: &u64, _to: &String) -> bool {
+        *amount >= 1_000_000
+    }
+
+    pub fn is_internal(account: &u32) -> bool {
+        *account < 100
+    }
+
+    pub fn is_large_transfer(src: &super::Src) -> bool {
+        matches!(src, super::Src::Transfer { amount, .. } if *amount >= 10_000)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Src {
+    Transfer { amount: u64, to: String },
+    Deposit(u32),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(Src)]
+enum Dest {
+    Transfer { amount: u64, to: String },
+
+    #[from_case(Transfer, if = rules::is_huge)]
+    HugeTransfer { amount: u64, to: String },
+
+    #[from_case(Src = Transfer, if = rules::is_large)]
+    LargeTransfer { amount: u64, to: String },
+
+    #[from_case(if = rules::is_internal, Deposit)]
+    InternalDeposit(u32),
+
+    Deposit(u32),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(Src)]
+enum Coarse {
+    #[from_case(Transfer, if = rules::is_large_transfer)]
+    LargeTransfer,
+    #[from_case(Transfer, Deposit)]
+    Other,
+}
+
+fn main() {
+    assert_eq!(
+        Dest::from(Src::Transfer { amount: 10, to: "a".to_string() }),
+        Dest::Transfer { amount: 10, to: "a".to_string() },
+    );
+    assert_eq!(
+        Dest::from(Src::Transfer { amount: 20_000, to: "b".to_string() }),
+        Dest::LargeTransfer { amount: 20_000, to: "b".to_string() },
+    );
+    assert_eq!(
+        Dest::from(Src::Transfer { amount: 2_000_000, to: "c".to_string() }),
+        Dest::HugeTransfer { amount: 2_000_000, to: "c".to_string() },
+    );
+    assert_eq!(Dest::from(Src::Deposit(5)), Dest::InternalDeposit(5));
+    assert_eq!(Dest::from(Src::Deposit(500)), Dest::Deposit(500));
+
+    assert_eq!(
+        Coarse::from(Src::Transfer { amount: 20_000, to: "d".to_string() }),
+        Coarse::LargeTransfer,
+    );
+    assert_eq!(
+        Coarse::from(Src::Transfer { amount: 20, to: "e".to_string() }),
+        Coarse::Other,
+    );
+    assert_eq!(Coarse::from(Src::Deposit(5)), Coarse::Other);
+}
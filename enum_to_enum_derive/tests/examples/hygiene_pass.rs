@@ -0,0 +1,142 @@
+use std::convert::TryFrom;
+use enum_to_enum::{FromEnum, FromEnumWith, WithEffects};
+
+// field and binding names that match the variables of the generated code
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Src {
+    Pair { a: String, a_value: String },
+    Named { value: String, effects: String },
+    Pick { a: u16, a_res: u16 },
+    Wrapped(String, String),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct EffectHolder<Value> {
+    value: Value,
+    effects: Vec<String>,
+}
+
+impl From<String> for EffectHolder<String> {
+    fn from(s: String) -> EffectHolder<String> {
+        let log = s.clone();
+        EffectHolder {
+            value: s,
+            effects: vec![log],
+        }
+    }
+}
+
+impl<Value> WithEffects for EffectHolder<Value> {
+    type Value = Value;
+    type Effect = String;
+
+    fn new(value: Self::Value, effects: Vec<Self::Effect>) -> Self {
+        Self { value, effects }
+    }
+
+    fn into_value_and_effects(self) -> (Self::Value, Box<dyn Iterator<Item = Self::Effect>>) {
+        (self.value, Box::new(self.effects.into_iter()))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(Src, partial)]
+enum Dest {
+    Pair { a: String, a_value: String },
+    Named { value: String, effects: String },
+    #[from_case(Pick)]
+    Narrow { a: u8, a_res: u8 },
+    #[from_case(Pick)]
+    Wide { a: u16, a_res: u16 },
+    #[from_case(Wrapped(src, ctx))]
+    Wrapped(String, String),
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(Src, effect_container = EffectHolder, partial)]
+enum EffectDest {
+    Pair { a: String, a_value: String },
+    Named { value: String, effects: String },
+}
+
+#[derive(Debug, Default)]
+struct Counter {
+    next: u32,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Numbered(u32, String);
+
+impl enum_to_enum::FromWith<String, Counter> for Numbered {
+    fn from_with(s: String, counter: &mut Counter) -> Numbered {
+        counter.next += 1;
+        Numbered(counter.next, s)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum CtxSrc {
+    Pair { ctx: String, src: String },
+    Wrapped(String),
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(CtxSrc, context = Counter)]
+enum CtxDest {
+    Pair { ctx: Numbered, src: Numbered },
+    #[from_case(Wrapped(ctx))]
+    Wrapped(Numbered),
+}
+
+fn s(value: &str) -> String {
+    value.to_string()
+}
+
+fn main() {
+    assert_eq!(
+        Dest::try_from(Src::Pair { a: s("a"), a_value: s("b") }),
+        Ok(Dest::Pair { a: s("a"), a_value: s("b") }),
+    );
+    assert_eq!(
+        Dest::try_from(Src::Named { value: s("v"), effects: s("e") }),
+        Ok(Dest::Named { value: s("v"), effects: s("e") }),
+    );
+    assert_eq!(
+        Dest::try_from(Src::Pick { a: 1, a_res: 2 }),
+        Ok(Dest::Narrow { a: 1, a_res: 2 }),
+    );
+    assert_eq!(
+        Dest::try_from(Src::Pick { a: 1, a_res: 300 }),
+        Ok(Dest::Wide { a: 1, a_res: 300 }),
+    );
+    assert_eq!(
+        Dest::try_from(Src::Wrapped(s("x"), s("y"))),
+        Ok(Dest::Wrapped(s("x"), s("y"))),
+    );
+
+    assert_eq!(
+        EffectHolder::<EffectDest>::try_from(Src::Pair { a: s("a"), a_value: s("b") }),
+        Ok(EffectHolder {
+            value: EffectDest::Pair { a: s("a"), a_value: s("b") },
+            effects: vec![s("a"), s("b")],
+        }),
+    );
+    assert_eq!(
+        EffectHolder::<EffectDest>::try_from(Src::Named { value: s("v"), effects: s("e") }),
+        Ok(EffectHolder {
+            value: EffectDest::Named { value: s("v"), effects: s("e") },
+            effects: vec![s("v"), s("e")],
+        }),
+    );
+
+    let mut counter = Counter::default();
+    assert_eq!(
+        CtxDest::from_with(CtxSrc::Pair { ctx: s("c"), src: s("s") }, &mut counter),
+        CtxDest::Pair { ctx: Numbered(1, s("c")), src: Numbered(2, s("s")) },
+    );
+    assert_eq!(
+        CtxDest::from_with(CtxSrc::Wrapped(s("w")), &mut counter),
+        CtxDest::Wrapped(Numbered(3, s("w"))),
+    );
+}
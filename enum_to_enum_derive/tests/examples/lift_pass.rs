@@ -0,0 +1,53 @@
+use enum_to_enum::FromEnum;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum NetEvent {
+    Connect(u32),
+    Disconnect { peer: u32, reason: String },
+    Ping,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DiskEvent {
+    Full,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Src {
+    Net(NetEvent),
+    Disk(Option<DiskEvent>),
+    Tick,
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(Src)]
+enum Dest {
+    #[from_case(Net(NetEvent::Connect))]
+    Connect(u64),
+
+    #[from_case(Src = Net(NetEvent::Disconnect))]
+    Disconnect { peer: u32, reason: String },
+
+    #[from_case(Disk(Some(DiskEvent::Full)))]
+    DiskFull,
+
+    #[from_case(Net(_), Disk(_))]
+    Other,
+
+    Tick,
+}
+
+fn main() {
+    assert_eq!(Dest::from(Src::Net(NetEvent::Connect(1))), Dest::Connect(1));
+    assert_eq!(
+        Dest::from(Src::Net(NetEvent::Disconnect {
+            peer: 2,
+            reason: "bye".to_string(),
+        })),
+        Dest::Disconnect { peer: 2, reason: "bye".to_string() },
+    );
+    assert_eq!(Dest::from(Src::Disk(Some(DiskEvent::Full))), Dest::DiskFull);
+    assert_eq!(Dest::from(Src::Net(NetEvent::Ping)), Dest::Other);
+    assert_eq!(Dest::from(Src::Disk(None)), Dest::Other);
+    assert_eq!(Dest::from(Src::Tick), Dest::Tick);
+}
@@ -0,0 +1,20 @@
+use enum_to_enum::FromEnum;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum NetEvent {
+    Connect(u32),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Src {
+    Net(NetEvent),
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(Src)]
+enum Dest {
+    #[from_case(NetEvent::Connect)]
+    Connect(u64),
+}
+
+fn main() {}
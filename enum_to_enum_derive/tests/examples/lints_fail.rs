@@ -0,0 +1,24 @@
+#![deny(warnings)]
+
+use enum_to_enum::FromEnum;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Src {
+    Reading(u32),
+    Level(u8),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(Src)]
+enum Dest {
+    #[from_case(Reading, infallible)]
+    Wide(u64),
+    #[from_case(Reading)]
+    Narrow(u8),
+    #[from_case(Level, Level)]
+    Level(u8),
+    #[from_case(except(Src))]
+    Orphan,
+}
+
+fn main() {}
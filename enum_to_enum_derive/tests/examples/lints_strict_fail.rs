@@ -0,0 +1,17 @@
+use enum_to_enum::FromEnum;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Src {
+    Reading(u32),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(Src, strict)]
+enum Dest {
+    #[from_case(Reading, infallible)]
+    Wide(u64),
+    #[from_case(Reading)]
+    Narrow(u8),
+}
+
+fn main() {}
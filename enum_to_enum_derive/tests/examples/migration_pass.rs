@@ -0,0 +1,124 @@
+use enum_to_enum::{FromEnum, Migration, Versioned, WithEffects};
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(migration)]
+pub enum EventV1 {
+    Click(u8),
+    Close,
+}
+
+pub enum LegacyEvent {
+    Click(u8),
+    Close,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(LegacyEvent)]
+#[from_enum(EventV1, migration)]
+pub enum EventV2 {
+    #[from_case(Click)]
+    Press(u16),
+    Close,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(EventV2, migration)]
+pub enum EventV3 {
+    Press(u32),
+    #[from_case(Close)]
+    Exit,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Note {
+    Widened(u32),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Noted<Value> {
+    value: Value,
+    effects: Vec<Note>,
+}
+
+impl<Value> WithEffects for Noted<Value> {
+    type Value = Value;
+    type Effect = Note;
+
+    fn new(value: Self::Value, effects: Vec<Self::Effect>) -> Self {
+        Self { value, effects }
+    }
+
+    fn into_value_and_effects(self) -> (Self::Value, Box<dyn Iterator<Item = Self::Effect>>) {
+        (self.value, Box::new(self.effects.into_iter()))
+    }
+}
+
+impl From<u8> for Noted<u16> {
+    fn from(u: u8) -> Noted<u16> {
+        Noted::new(u.into(), vec![Note::Widened(u.into())])
+    }
+}
+
+impl From<u16> for Noted<u32> {
+    fn from(u: u16) -> Noted<u32> {
+        Noted::new(u.into(), vec![Note::Widened(u.into())])
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(migration, effect_container = Noted)]
+enum LogV1 {
+    Entry(u8),
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(LogV1, migration, effect_container = Noted)]
+enum LogV2 {
+    Entry(u16),
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(LogV2, migration, effect_container = Noted)]
+enum LogV3 {
+    Entry(u32),
+}
+
+fn main() {
+    assert_eq!(EventV1::VERSION, 1);
+    assert_eq!(EventV3::VERSION, 3);
+
+    let stored = EventV3Versioned::from(EventV1::Click(1));
+    assert_eq!(
+        stored,
+        EventV3Versioned::Previous(EventV2Versioned::Previous(EventV1Versioned::from(
+            EventV1::Click(1),
+        ))),
+    );
+    assert_eq!(
+        format!("{:?}", stored),
+        "Previous(Previous(EventV1(Click(1))))",
+    );
+    assert_eq!(stored.clone().version(), 1);
+    assert_eq!(stored.version(), 1);
+    assert_eq!(stored.migrate_to_latest(), EventV3::Press(1));
+
+    assert_eq!(EventV2::from(LegacyEvent::Click(2)), EventV2::Press(2));
+
+    let stored = EventV3Versioned::from(EventV2::Close);
+    assert_eq!(stored.version(), 2);
+    assert_eq!(stored.migrate_to_latest(), EventV3::Exit);
+
+    let stored = EventV3Versioned::from(EventV3::Press(3));
+    assert_eq!(stored.version(), 3);
+    assert_eq!(stored.migrate_to_latest(), EventV3::Press(3));
+
+    let stored = LogV3Versioned::from(LogV1::Entry(4));
+    assert_eq!(format!("{:?}", stored), "Previous(Previous(LogV1(Entry(4))))");
+    assert_eq!(
+        stored.migrate_to_latest(),
+        Noted {
+            value: LogV3::Entry(4),
+            effects: vec![Note::Widened(4), Note::Widened(4)],
+        },
+    );
+}
@@ -0,0 +1,24 @@
+use enum_to_enum::FromEnum;
+
+enum EventV1 {
+    Click,
+}
+
+enum LegacyEvent {
+    Click,
+}
+
+#[derive(FromEnum)]
+#[from_enum(EventV1, LegacyEvent, migration)]
+enum EventV2 {
+    Click,
+}
+
+#[derive(FromEnum)]
+#[from_enum(EventV1, migration)]
+#[from_enum(LegacyEvent, migration)]
+enum EventV3 {
+    Click,
+}
+
+fn main() {}
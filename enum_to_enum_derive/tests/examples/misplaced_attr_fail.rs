@@ -0,0 +1,18 @@
+use enum_to_enum::FromEnum;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Src {
+    Case1(String),
+    Case2(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(Src)]
+#[from_case(Case1)]
+enum Dest {
+    #[from_enum(Src)]
+    Case1(String),
+    Case2(#[from_case(Case1)] String),
+}
+
+fn main() {}
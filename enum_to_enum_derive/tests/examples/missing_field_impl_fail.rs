@@ -0,0 +1,19 @@
+use enum_to_enum::FromEnum;
+
+struct Meters(u32);
+
+struct Feet(u32);
+
+enum Src {
+    Height(Meters),
+    Depth(Meters),
+}
+
+#[derive(FromEnum)]
+#[from_enum(Src)]
+enum Dest {
+    Height(Meters),
+    Depth(Feet),
+}
+
+fn main() {}
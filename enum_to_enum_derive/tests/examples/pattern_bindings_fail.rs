@@ -0,0 +1,18 @@
+use enum_to_enum::FromEnum;
+
+enum Src {
+    Http { status: u16, body: String },
+}
+
+#[derive(FromEnum)]
+#[from_enum(Src)]
+enum Dest {
+    #[from_case(Http { status: 500..=599, body, .. })]
+    ServerError { message: String },
+    #[from_case(Http { status: 0..=499, .. })]
+    Other(u16),
+    #[from_case(Http)]
+    Http { status: u16, body: String },
+}
+
+fn main() {}
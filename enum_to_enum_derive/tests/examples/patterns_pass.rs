@@ -0,0 +1,70 @@
+use enum_to_enum::FromEnum;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Kind {
+    Click,
+    Hover,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Pos(u32, u32);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Src {
+    Event(Kind, Pos),
+    Http { status: u16, body: String },
+    Idle(),
+    Pair(Option<u32>, u32),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(Src)]
+enum Dest {
+    #[from_case(Event(Kind::Click, pos))]
+    Click(Pos),
+
+    #[from_case(Src = Event(Kind::Hover, pos))]
+    Hover { pos: Pos },
+
+    #[from_case(Src = Http { status: 500..=599, body, .. })]
+    ServerError(String),
+
+    #[from_case(Http { status: code @ 400..=499, .. })]
+    ClientError { code: u16 },
+
+    Http { status: u16, body: String },
+
+    Idle(),
+
+    #[from_case(Pair(None, b))]
+    Single(u32),
+
+    #[from_case(Pair(Some(a), b))]
+    Both(u32, u32),
+}
+
+fn main() {
+    assert_eq!(
+        Dest::from(Src::Event(Kind::Click, Pos(1, 2))),
+        Dest::Click(Pos(1, 2)),
+    );
+    assert_eq!(
+        Dest::from(Src::Event(Kind::Hover, Pos(3, 4))),
+        Dest::Hover { pos: Pos(3, 4) },
+    );
+    assert_eq!(
+        Dest::from(Src::Http { status: 503, body: "down".to_string() }),
+        Dest::ServerError("down".to_string()),
+    );
+    assert_eq!(
+        Dest::from(Src::Http { status: 200, body: "ok".to_string() }),
+        Dest::Http { status: 200, body: "ok".to_string() },
+    );
+    assert_eq!(
+        Dest::from(Src::Http { status: 404, body: "missing".to_string() }),
+        Dest::ClientError { code: 404 },
+    );
+    assert_eq!(Dest::from(Src::Idle()), Dest::Idle());
+    assert_eq!(Dest::from(Src::Pair(None, 5)), Dest::Single(5));
+    assert_eq!(Dest::from(Src::Pair(Some(6), 7)), Dest::Both(6, 7));
+}
@@ -0,0 +1,70 @@
+use enum_to_enum::{FromEnum, WithEffects};
+use std::convert::TryFrom;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Live {
+    Click(u8),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Replayed {
+    Click(u8),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Raw {
+    Click(u8),
+    Noise,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Log {
+    Converted(u8),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Logged<Value> {
+    value: Value,
+    effects: Vec<Log>,
+}
+
+impl<Value> WithEffects for Logged<Value> {
+    type Value = Value;
+    type Effect = Log;
+
+    fn new(value: Self::Value, effects: Vec<Self::Effect>) -> Self {
+        Self { value, effects }
+    }
+
+    fn into_value_and_effects(self) -> (Self::Value, Box<dyn Iterator<Item = Self::Effect>>) {
+        (self.value, Box::new(self.effects.into_iter()))
+    }
+}
+
+impl From<u8> for Logged<u16> {
+    fn from(u: u8) -> Logged<u16> {
+        Logged::new(u.into(), vec![Log::Converted(u)])
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(Live, effect_container = Logged)]
+#[from_enum(Replayed)]
+#[from_enum(Raw, partial)]
+#[from_enum(Replayed)]
+enum Event {
+    Click(u16),
+}
+
+fn main() {
+    assert_eq!(
+        Logged::<Event>::from(Live::Click(1)),
+        Logged {
+            value: Event::Click(1),
+            effects: vec![Log::Converted(1)],
+        },
+    );
+    assert_eq!(Event::from(Replayed::Click(2)), Event::Click(2));
+    assert_eq!(Event::try_from(Raw::Click(3)), Ok(Event::Click(3)));
+    assert_eq!(Event::try_from(Raw::Noise), Err(Raw::Noise));
+}
@@ -0,0 +1,25 @@
+use enum_to_enum::FromEnum;
+
+#[derive(Clone)]
+enum Src {
+    Amount(u32),
+}
+
+#[derive(FromEnum)]
+#[from_enum(Src)]
+enum Dest {
+    #[from_case(Amount, priority = 1)]
+    Small(u32),
+
+    #[from_case(Amount, priority = 1)]
+    Big(u32),
+}
+
+#[derive(FromEnum)]
+#[from_enum(Src)]
+enum Negative {
+    #[from_case(Amount, priority = -1)]
+    Amount(u32),
+}
+
+fn main() {}
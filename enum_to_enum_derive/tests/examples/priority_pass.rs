@@ -0,0 +1,74 @@
+use enum_to_enum::FromEnum;
+use std::convert::TryFrom;
+
+#[derive(Debug, Clone)]
+enum Src {
+    Amount(u32),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Small(u32);
+
+#[derive(Debug, PartialEq, Eq)]
+struct Even(u32);
+
+impl TryFrom<u32> for Small {
+    type Error = ();
+
+    fn try_from(u: u32) -> Result<Small, ()> {
+        if u < 100 {
+            Ok(Small(u))
+        } else {
+            Err(())
+        }
+    }
+}
+
+impl TryFrom<u32> for Even {
+    type Error = ();
+
+    fn try_from(u: u32) -> Result<Even, ()> {
+        if u % 2 == 0 {
+            Ok(Even(u))
+        } else {
+            Err(())
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(Src)]
+enum Dest {
+    #[from_case(Amount)]
+    Any(u32),
+
+    #[from_case(Amount, priority = 1)]
+    Small(Small),
+
+    #[from_case(Amount, priority = 2)]
+    Even(Even),
+}
+
+// candidates with patterns have match arms of their own, so they may share a priority
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(Src)]
+enum Band {
+    #[from_case(Amount(0..=99), priority = 1)]
+    Low,
+
+    #[from_case(Amount(100..=999), priority = 1)]
+    High,
+
+    #[from_case(Amount)]
+    Other,
+}
+
+fn main() {
+    assert_eq!(Dest::from(Src::Amount(4)), Dest::Even(Even(4)));
+    assert_eq!(Dest::from(Src::Amount(5)), Dest::Small(Small(5)));
+    assert_eq!(Dest::from(Src::Amount(501)), Dest::Any(501));
+
+    assert_eq!(Band::from(Src::Amount(5)), Band::Low);
+    assert_eq!(Band::from(Src::Amount(500)), Band::High);
+    assert_eq!(Band::from(Src::Amount(5000)), Band::Other);
+}
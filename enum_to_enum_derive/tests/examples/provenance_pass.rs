@@ -0,0 +1,105 @@
+use enum_to_enum::FromEnum;
+
+mod legacy {
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum Event {
+        Login(u32),
+        Logout { user: u32 },
+        Timeout { user: u32 },
+        Other,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Event {
+    Login(u32),
+    Expire { user: u32 },
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(legacy::Event, Event)]
+enum Audit {
+    #[from_case(Login)]
+    Session(u32, #[from_field(source_enum)] &'static str),
+
+    #[from_case(legacy::Event = Logout, legacy::Event = Timeout, Event = Expire)]
+    Ended {
+        user: u32,
+        #[from_field(source_variant)]
+        reason: &'static str,
+        #[from_field(source_enum)]
+        origin: &'static str,
+    },
+
+    #[from_case(legacy::Event = _)]
+    Unknown(#[from_field(source_enum)] &'static str),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Input {
+    Click(u8),
+    Tap(u8),
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(Input)]
+enum Pointer {
+    #[from_case(Click(button @ 0..=2), Tap(button @ 0..=2))]
+    Press(#[from_field(source_variant)] &'static str, u8),
+
+    #[from_case(Click, Tap)]
+    Other(u8, #[from_field(source_variant)] &'static str),
+}
+
+mod other_crate {
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum Signal {
+        Start(u8),
+        Stop,
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(remote = "other_crate::Signal")]
+enum SignalDef {
+    Start(#[from_field(source_variant)] &'static str, u8),
+    Stop {
+        #[from_field(source_enum)]
+        origin: &'static str,
+    },
+}
+
+fn main() {
+    assert_eq!(
+        Audit::from(legacy::Event::Login(1)),
+        Audit::Session(1, "legacy::Event"),
+    );
+    assert_eq!(Audit::from(Event::Login(2)), Audit::Session(2, "Event"));
+    assert_eq!(
+        Audit::from(legacy::Event::Timeout { user: 3 }),
+        Audit::Ended { user: 3, reason: "Timeout", origin: "legacy::Event" },
+    );
+    assert_eq!(
+        Audit::from(legacy::Event::Logout { user: 4 }),
+        Audit::Ended { user: 4, reason: "Logout", origin: "legacy::Event" },
+    );
+    assert_eq!(
+        Audit::from(Event::Expire { user: 5 }),
+        Audit::Ended { user: 5, reason: "Expire", origin: "Event" },
+    );
+    assert_eq!(Audit::from(legacy::Event::Other), Audit::Unknown("legacy::Event"));
+    assert_eq!(Pointer::from(Input::Tap(1)), Pointer::Press("Tap", 1));
+    assert_eq!(Pointer::from(Input::Click(7)), Pointer::Other(7, "Click"));
+    assert_eq!(
+        SignalDef::from(other_crate::Signal::Start(3)),
+        SignalDef::Start("Start", 3),
+    );
+    assert_eq!(
+        other_crate::Signal::from(SignalDef::Start("Start", 4)),
+        other_crate::Signal::Start(4),
+    );
+    assert_eq!(
+        other_crate::Signal::from(SignalDef::Stop { origin: "other_crate::Signal" }),
+        other_crate::Signal::Stop,
+    );
+}
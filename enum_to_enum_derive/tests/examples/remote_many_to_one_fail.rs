@@ -0,0 +1,18 @@
+use enum_to_enum::FromEnum;
+
+mod other_crate {
+    pub enum Kind {
+        Click(u32),
+    }
+}
+
+#[derive(FromEnum)]
+#[from_enum(remote = "other_crate::Kind")]
+enum KindDef {
+    #[from_case(Click)]
+    SmallClick(u8),
+    #[from_case(Click)]
+    Click(u32),
+}
+
+fn main() {}
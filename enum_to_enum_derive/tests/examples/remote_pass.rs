@@ -0,0 +1,61 @@
+use enum_to_enum::FromEnum;
+
+mod other_crate {
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum Kind {
+        Click(u32, u32),
+        KeyPress { key: char },
+        Idle,
+    }
+}
+
+mod third_crate {
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum Other {
+        Pointer(u32, u32),
+        Key { key: char },
+        Idle,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(remote = "other_crate::Kind")]
+enum KindDef {
+    Click(u32, u32),
+    #[from_case(KeyPress)]
+    Key { key: char },
+    Idle,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(KindDef, remote = "third_crate::Other")]
+enum OtherDef {
+    #[from_case(KindDef = Click, third_crate::Other = Pointer)]
+    Pointer(u32, u32),
+    Key { key: char },
+    Idle,
+}
+
+fn convert(kind: other_crate::Kind) -> third_crate::Other {
+    OtherDef::from(KindDef::from(kind)).into()
+}
+
+fn main() {
+    assert_eq!(
+        KindDef::from(other_crate::Kind::KeyPress { key: 'a' }),
+        KindDef::Key { key: 'a' },
+    );
+    assert_eq!(
+        other_crate::Kind::from(KindDef::Key { key: 'b' }),
+        other_crate::Kind::KeyPress { key: 'b' },
+    );
+    assert_eq!(other_crate::Kind::from(KindDef::Idle), other_crate::Kind::Idle);
+    assert_eq!(
+        convert(other_crate::Kind::Click(1, 2)),
+        third_crate::Other::Pointer(1, 2),
+    );
+    assert_eq!(
+        OtherDef::from(third_crate::Other::Key { key: 'c' }),
+        OtherDef::Key { key: 'c' },
+    );
+}
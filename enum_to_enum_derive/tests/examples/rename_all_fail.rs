@@ -0,0 +1,32 @@
+use enum_to_enum::FromEnum;
+
+#[allow(non_camel_case_types)]
+enum Src {
+    CASE_1,
+}
+
+#[derive(FromEnum)]
+#[from_enum(Src, rename_all = "SHOUTY_CASE")]
+enum Dest {
+    Case1,
+}
+
+
+#[derive(FromEnum)]
+#[from_enum(Src, strip_prefix = "Event Type")]
+enum Spaced {
+    Case1,
+}
+
+#[allow(non_camel_case_types)]
+enum Lower {
+    r#type,
+}
+
+#[derive(FromEnum)]
+#[from_enum(Lower, rename_all = "lowercase")]
+enum Keyword {
+    Type,
+}
+
+fn main() {}
@@ -0,0 +1,50 @@
+use enum_to_enum::FromEnum;
+
+mod proto {
+    #[allow(non_camel_case_types)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum EventType {
+        EVENT_TYPE_CLICK,
+        EVENT_TYPE_KEY_PRESS(char),
+        EVENT_TYPE_UNSPECIFIED,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Legacy {
+    LegacyClickEvent,
+    LegacyKeyPressEvent(char),
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Current {
+    Click,
+    KeyPress(char),
+    Idle,
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(Current)]
+#[from_enum(proto::EventType, rename_all = "SCREAMING_SNAKE_CASE", strip_prefix = "EventType")]
+#[from_enum(Legacy, strip_prefix = "Legacy", strip_suffix = "Event")]
+enum Event {
+    Click,
+    KeyPress(char),
+    #[from_case(Current = Idle, proto::EventType = EVENT_TYPE_UNSPECIFIED, Legacy = Unknown)]
+    Other,
+}
+
+fn main() {
+    assert_eq!(Event::from(Current::Click), Event::Click);
+    assert_eq!(Event::from(proto::EventType::EVENT_TYPE_CLICK), Event::Click);
+    assert_eq!(
+        Event::from(proto::EventType::EVENT_TYPE_KEY_PRESS('a')),
+        Event::KeyPress('a'),
+    );
+    assert_eq!(Event::from(proto::EventType::EVENT_TYPE_UNSPECIFIED), Event::Other);
+    assert_eq!(Event::from(Legacy::LegacyKeyPressEvent('b')), Event::KeyPress('b'));
+    assert_eq!(Event::from(Legacy::LegacyClickEvent), Event::Click);
+    assert_eq!(Event::from(Legacy::Unknown), Event::Other);
+    assert_eq!(Event::from(Current::Idle), Event::Other);
+}
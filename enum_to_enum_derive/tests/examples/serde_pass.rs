@@ -0,0 +1,77 @@
+use enum_to_enum::FromEnum;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+enum EventV1 {
+    Click { x: u8 },
+    Scroll { delta: u8 },
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type")]
+enum LegacyEvent {
+    Tap { x: u8 },
+    Wheel { delta: u8 },
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, FromEnum)]
+#[from_enum(EventV1, deserialize)]
+#[from_enum(LegacyEvent)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum EventV2 {
+    #[from_case(EventV1 = Click, LegacyEvent = Tap)]
+    Click { x: u16 },
+    #[from_case(Scroll, LegacyEvent = Wheel)]
+    Scroll {
+        #[serde(rename = "amount")]
+        delta: u16,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(EventV1, deserialize)]
+#[serde(tag = "rename")]
+enum Tagged {
+    Click { x: u8 },
+    Scroll { delta: u8 },
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(EventV1, deserialize)]
+#[serde(rename(deserialize = "Event"))]
+enum Renamed {
+    Click { x: u8 },
+    Scroll { delta: u8 },
+}
+
+fn main() {
+    let current = EventV2::Scroll { delta: 500 };
+    let json = serde_json::to_string(&current).unwrap();
+    assert_eq!(json, r#"{"kind":"scroll","amount":500}"#);
+    assert_eq!(serde_json::from_str::<EventV2>(&json).unwrap(), current);
+
+    assert_eq!(
+        serde_json::from_str::<EventV2>(r#"{"Scroll":{"delta":7}}"#).unwrap(),
+        EventV2::Scroll { delta: 7 },
+    );
+    assert_eq!(
+        serde_json::from_str::<EventV2>(r#"{"type":"Tap","x":3}"#).unwrap(),
+        EventV2::Click { x: 3 },
+    );
+
+    assert_eq!(
+        serde_json::from_str::<Tagged>(r#"{"rename":"Click","x":4}"#).unwrap(),
+        Tagged::Click { x: 4 },
+    );
+    assert_eq!(
+        serde_json::from_str::<Renamed>(r#"{"Scroll":{"delta":5}}"#).unwrap(),
+        Renamed::Scroll { delta: 5 },
+    );
+
+    let err = serde_json::from_str::<EventV2>(r#"{"Hover":1}"#)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("as EventV2: "), "{}", err);
+    assert!(err.contains("as EventV1: "), "{}", err);
+    assert!(err.contains("as LegacyEvent: "), "{}", err);
+}
@@ -0,0 +1,20 @@
+use enum_to_enum::FromEnum;
+
+#[derive(Debug)]
+enum Src1 {
+    Running(u8),
+    Paused,
+}
+
+#[derive(Debug, FromEnum)]
+#[from_enum(Src1)]
+enum Dest {
+    #[from_case(Running, skip)]
+    Running(u8),
+    #[from_case(except(Src3))]
+    Paused,
+    #[from_case(Src1 = Paused, except(Src1))]
+    Stopped,
+}
+
+fn main() {}
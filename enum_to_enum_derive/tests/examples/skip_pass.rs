@@ -0,0 +1,33 @@
+use enum_to_enum::FromEnum;
+
+#[derive(Debug)]
+enum Src1 {
+    Running(u8),
+    Paused,
+}
+
+#[derive(Debug)]
+enum Src2 {
+    Running(u8),
+    Stopped,
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(Src1, Src2)]
+enum Dest {
+    Running(u8),
+    #[from_case(except(Src2))]
+    Paused,
+    #[from_case(except(Src1))]
+    Stopped,
+    #[from_case(skip)]
+    Unknown,
+}
+
+fn main() {
+    assert_eq!(Dest::from(Src1::Running(3)), Dest::Running(3));
+    assert_eq!(Dest::from(Src1::Paused), Dest::Paused);
+    assert_eq!(Dest::from(Src2::Running(3)), Dest::Running(3));
+    assert_eq!(Dest::from(Src2::Stopped), Dest::Stopped);
+    assert_ne!(Dest::Unknown, Dest::Paused);
+}
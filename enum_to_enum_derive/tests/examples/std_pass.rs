@@ -0,0 +1,101 @@
+use enum_to_enum::FromEnum;
+use std::cmp::Ordering;
+use std::io;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Foo(u8);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Bar(u16);
+
+impl From<Foo> for Bar {
+    fn from(foo: Foo) -> Bar {
+        Bar(foo.0.into())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(Option<Foo>)]
+enum Presence {
+    #[from_case(Some)]
+    Present(Bar),
+    #[from_case(None)]
+    Absent,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(Result<Foo, String>, std::result::Result<u16, io::Error>)]
+enum Status {
+    #[from_case(Result<Foo, String> = Ok, std::result::Result<u16, io::Error> = Ok)]
+    Done(Bar),
+    #[from_case(Result<Foo, String> = Err)]
+    Failed(String),
+    #[from_case(std::result::Result<u16, io::Error> = Err)]
+    IoFailed(IoFailure),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct IoFailure(io::ErrorKind);
+
+impl From<io::Error> for IoFailure {
+    fn from(err: io::Error) -> IoFailure {
+        IoFailure(err.kind())
+    }
+}
+
+impl From<u16> for Bar {
+    fn from(u: u16) -> Bar {
+        Bar(u)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(Ordering)]
+enum Comparison {
+    #[from_case(Less)]
+    Before,
+    #[from_case(Equal)]
+    Same,
+    #[from_case(Greater)]
+    After,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(io::ErrorKind)]
+enum IoErrorKind {
+    NotFound,
+    PermissionDenied,
+    #[from_case(_)]
+    Other(io::ErrorKind),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(io::ErrorKind)]
+enum Retry {
+    #[from_case(Interrupted, WouldBlock, TimedOut)]
+    Retry,
+    #[from_case(_)]
+    GiveUp,
+}
+
+fn main() {
+    assert_eq!(Presence::from(Some(Foo(1))), Presence::Present(Bar(1)));
+    assert_eq!(Presence::from(None::<Foo>), Presence::Absent);
+    assert_eq!(Status::from(Ok::<Foo, String>(Foo(2))), Status::Done(Bar(2)));
+    assert_eq!(
+        Status::from(Err::<Foo, String>("nope".to_string())),
+        Status::Failed("nope".to_string()),
+    );
+    assert_eq!(
+        Status::from(Err::<u16, io::Error>(io::Error::from(io::ErrorKind::NotFound))),
+        Status::IoFailed(IoFailure(io::ErrorKind::NotFound)),
+    );
+    assert_eq!(Comparison::from(1.cmp(&2)), Comparison::Before);
+    assert_eq!(IoErrorKind::from(io::ErrorKind::NotFound), IoErrorKind::NotFound);
+    assert_eq!(
+        IoErrorKind::from(io::ErrorKind::UnexpectedEof),
+        IoErrorKind::Other(io::ErrorKind::UnexpectedEof),
+    );
+    assert_eq!(Retry::from(io::ErrorKind::TimedOut), Retry::Retry);
+    assert_eq!(Retry::from(io::ErrorKind::NotFound), Retry::GiveUp);
+}
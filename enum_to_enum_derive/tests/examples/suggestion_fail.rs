@@ -0,0 +1,28 @@
+use enum_to_enum::FromEnum;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Source {
+    Case1(String),
+    Case2(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(Source)]
+enum Dest {
+    #[from_case(Sourse = Case2)]
+    Case1(String),
+    Case2 {
+        value: String,
+        #[from_field(source_varaint)]
+        variant: &'static str,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(Source, parital)]
+enum Flagged {
+    Case1(String),
+    Case2(String),
+}
+
+fn main() {}
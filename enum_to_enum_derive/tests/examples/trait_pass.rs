@@ -0,0 +1,118 @@
+use enum_to_enum::{FromEnum, WithEffects};
+
+mod convert {
+    pub trait Convert<T> {
+        fn convert(t: T) -> Self;
+    }
+
+    impl Convert<u8> for u8 {
+        fn convert(t: u8) -> u8 {
+            t
+        }
+    }
+
+    impl Convert<u8> for String {
+        fn convert(t: u8) -> String {
+            format!("#{}", t)
+        }
+    }
+}
+
+use convert::Convert;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Src {
+    Case1(u8),
+    Case2 { a: u8, b: u8 },
+    Case3(),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(Src, trait = convert::Convert, method = convert)]
+enum SimpleDest {
+    Case1(String),
+    Case2 { a: String, b: u8 },
+    #[from_case(Case3)]
+    MyCase3(),
+}
+
+enum Outer {
+    Wrapped(Src),
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(Outer, trait = Convert, method = convert)]
+enum NestedDest {
+    Wrapped(SimpleDest),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum MyEffect {
+    Log(String),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct EffectHolder<Value> {
+    value: Value,
+    effects: Vec<MyEffect>,
+}
+
+impl<Value> WithEffects for EffectHolder<Value> {
+    type Value = Value;
+    type Effect = MyEffect;
+
+    fn new(value: Self::Value, effects: Vec<Self::Effect>) -> Self {
+        Self { value, effects }
+    }
+
+    fn into_value_and_effects(self) -> (Self::Value, Box<dyn Iterator<Item = Self::Effect>>) {
+        (self.value, Box::new(self.effects.into_iter()))
+    }
+}
+
+impl Convert<u8> for EffectHolder<String> {
+    fn convert(u: u8) -> EffectHolder<String> {
+        EffectHolder {
+            value: String::convert(u),
+            effects: vec![MyEffect::Log(format!("converted {}", u))],
+        }
+    }
+}
+
+impl Convert<u8> for EffectHolder<u8> {
+    fn convert(u: u8) -> EffectHolder<u8> {
+        EffectHolder {
+            value: u,
+            effects: vec![],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(Src, effect_container = EffectHolder, trait = Convert, method = convert)]
+enum EffectDest {
+    Case1(String),
+    Case2 { a: String, b: u8 },
+    #[from_case(Case3)]
+    MyCase3(),
+}
+
+fn main() {
+    assert_eq!(SimpleDest::convert(Src::Case1(1)), SimpleDest::Case1("#1".to_string()));
+    assert_eq!(
+        SimpleDest::convert(Src::Case2 { a: 2, b: 3 }),
+        SimpleDest::Case2 { a: "#2".to_string(), b: 3 },
+    );
+    assert_eq!(SimpleDest::convert(Src::Case3()), SimpleDest::MyCase3());
+    assert_eq!(
+        NestedDest::convert(Outer::Wrapped(Src::Case1(4))),
+        NestedDest::Wrapped(SimpleDest::Case1("#4".to_string())),
+    );
+    assert_eq!(
+        EffectHolder::<EffectDest>::convert(Src::Case2 { a: 5, b: 6 }),
+        EffectHolder {
+            value: EffectDest::Case2 { a: "#5".to_string(), b: 6 },
+            effects: vec![MyEffect::Log("converted 5".to_string())],
+        },
+    );
+}
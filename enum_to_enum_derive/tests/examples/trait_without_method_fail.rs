@@ -0,0 +1,17 @@
+use enum_to_enum::FromEnum;
+
+pub trait Convert<T> {
+    fn convert(t: T) -> Self;
+}
+
+enum Src {
+    Case1(u8),
+}
+
+#[derive(FromEnum)]
+#[from_enum(Src, trait = Convert)]
+enum Dest {
+    Case1(u8),
+}
+
+fn main() {}
@@ -0,0 +1,49 @@
+use enum_to_enum::FromEnum;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Src {
+    Running(u8),
+    Paused,
+    Stopped,
+    Level(u16),
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(Src)]
+enum Dest {
+    Running(u8),
+    #[from_case(Paused, Stopped)]
+    Inactive,
+    #[from_case(Level, priority = 1)]
+    Level(u8),
+    #[from_case(Level)]
+    Overflow,
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(Src)]
+enum Coarse {
+    #[from_case(Running, if = is_fast)]
+    Fast(u8),
+    #[from_case(Running, Level)]
+    Busy,
+    #[from_case(Paused, Stopped)]
+    Idle,
+}
+
+fn is_fast(speed: &u8) -> bool {
+    *speed > 100
+}
+
+fn main() {
+    assert_eq!(Dest::from(Src::Running(3)), Dest::Running(3));
+    assert_eq!(Dest::from(Src::Paused), Dest::Inactive);
+    assert_eq!(Dest::from(Src::Stopped), Dest::Inactive);
+    assert_eq!(Dest::from(Src::Level(7)), Dest::Level(7));
+    assert_eq!(Dest::from(Src::Level(700)), Dest::Overflow);
+
+    assert_eq!(Coarse::from(Src::Running(200)), Coarse::Fast(200));
+    assert_eq!(Coarse::from(Src::Running(3)), Coarse::Busy);
+    assert_eq!(Coarse::from(Src::Level(3)), Coarse::Busy);
+    assert_eq!(Coarse::from(Src::Stopped), Coarse::Idle);
+}
@@ -0,0 +1,18 @@
+use enum_to_enum::FromEnum;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Src {
+    Case1(String),
+    Case2(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(Src)]
+enum Dest {
+    #[from_case(Source = Case1)]
+    Case1(String),
+    #[from_case(Other = Case1)]
+    Case2(String),
+}
+
+fn main() {}
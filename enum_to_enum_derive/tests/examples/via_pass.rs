@@ -0,0 +1,99 @@
+use enum_to_enum::{FromEnum, WithEffects};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum V1 {
+    Click(u8),
+    Scroll(u8),
+    Legacy(u8),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(V1)]
+enum V2 {
+    Click(u16),
+    #[from_case(Scroll, Legacy)]
+    Scroll(u16),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(V2)]
+#[from_enum(V1, via = V2)]
+enum V3 {
+    Click(u32),
+    Scroll(u32),
+    #[from_case(V1 = Legacy)]
+    Legacy(u32),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Log {
+    Converted(u32),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Logged<Value> {
+    value: Value,
+    effects: Vec<Log>,
+}
+
+impl<Value> WithEffects for Logged<Value> {
+    type Value = Value;
+    type Effect = Log;
+
+    fn new(value: Self::Value, effects: Vec<Self::Effect>) -> Self {
+        Self { value, effects }
+    }
+
+    fn into_value_and_effects(self) -> (Self::Value, Box<dyn Iterator<Item = Self::Effect>>) {
+        (self.value, Box::new(self.effects.into_iter()))
+    }
+}
+
+impl From<u8> for Logged<u16> {
+    fn from(u: u8) -> Logged<u16> {
+        Logged::new(u.into(), vec![Log::Converted(u.into())])
+    }
+}
+
+impl From<u16> for Logged<u32> {
+    fn from(u: u16) -> Logged<u32> {
+        Logged::new(u.into(), vec![Log::Converted(u.into())])
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(V1, effect_container = Logged)]
+enum LoggedV2 {
+    #[from_case(Click, Scroll, Legacy)]
+    Any(u16),
+}
+
+#[derive(Debug, PartialEq, Eq, FromEnum)]
+#[from_enum(LoggedV2, effect_container = Logged)]
+#[from_enum(V1, via = LoggedV2, effect_container = Logged)]
+enum LoggedV3 {
+    Any(u32),
+}
+
+// the second hop uses the conversion we generate from the intermediate, here a function
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(V2, fn = from_v2)]
+#[from_enum(V1, via = V2, fn = from_v1)]
+enum NamedV3 {
+    Click(u32),
+    Scroll(u32),
+}
+
+fn main() {
+    assert_eq!(V3::from(V1::Click(1)), V3::Click(1));
+    assert_eq!(V3::from(V1::Scroll(2)), V3::Scroll(2));
+    assert_eq!(V3::from(V1::Legacy(3)), V3::Legacy(3));
+    assert_eq!(NamedV3::from_v1(V1::Legacy(5)), NamedV3::Scroll(5));
+    assert_eq!(
+        Logged::<LoggedV3>::from(V1::Scroll(4)),
+        Logged {
+            value: LoggedV3::Any(4),
+            effects: vec![Log::Converted(4), Log::Converted(4)],
+        },
+    );
+}
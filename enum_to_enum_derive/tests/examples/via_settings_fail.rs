@@ -0,0 +1,21 @@
+use enum_to_enum::FromEnum;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum V1 {
+    Click(u8),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(V1)]
+enum V2 {
+    Click(u16),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromEnum)]
+#[from_enum(V2, partial)]
+#[from_enum(V1, via = V2)]
+enum V3 {
+    Click(u32),
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use enum_to_enum::FromEnum;
+
+enum Src {
+    Case1(u8),
+    Case2(u8, u8),
+}
+
+#[derive(FromEnum)]
+#[from_enum(Src)]
+enum Dest {
+    Case1(u8),
+    #[from_case(_)]
+    Other(u8, u8),
+}
+
+fn main() {}